@@ -0,0 +1,65 @@
+//! Record the compiler, target and source revision for artifacts, since none of them is known at
+//! run time: the binary can run anywhere, long after the checkout it was built from has moved on.
+
+use std::{env, path::Path, process::Command};
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        // Only look: refreshing the index would change it and so rerun this script.
+        .env("GIT_OPTIONAL_LOCKS", "0")
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+fn main() {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .unwrap_or_default();
+    println!("cargo:rustc-env=AOC_RUSTC_VERSION={}", version.trim());
+    println!(
+        "cargo:rustc-env=AOC_TARGET={}",
+        env::var("TARGET").unwrap_or_default()
+    );
+
+    // Both are empty outside a git checkout, such as a packaged crate.
+    let commit = git(&["rev-parse", "HEAD"]).unwrap_or_default();
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.is_empty());
+    println!("cargo:rustc-env=AOC_COMMIT={commit}");
+    println!("cargo:rustc-env=AOC_DIRTY={dirty}");
+
+    // The shared modules (grid, scanner and so on) are only partly used by any one day, so with
+    // fewer days they warn about dead code that some other day needs. Building every day is the
+    // build that can tell genuinely dead code apart, and this marks it.
+    println!("cargo:rustc-check-cfg=cfg(all_days)");
+    if (1..=16).all(|day| env::var_os(format!("CARGO_FEATURE_DAY{day:02}")).is_some()) {
+        println!("cargo:rustc-cfg=all_days");
+    }
+
+    println!("cargo:rerun-if-changed=build.rs");
+    // Commits and checkouts move HEAD or the branch it's on, and staging changes the index. Edits
+    // to the sources rebuild the crate but not this script, so they're watched too.
+    if let Some(git_dir) = git(&["rev-parse", "--git-dir"]) {
+        let branch = git(&["symbolic-ref", "-q", "HEAD"]);
+        let watched = ["HEAD", "index", "packed-refs"]
+            .into_iter()
+            .chain(branch.as_deref())
+            .map(|path| Path::new(&git_dir).join(path));
+        // Cargo reruns the script every build for a path that doesn't exist.
+        for path in watched.filter(|path| path.exists()) {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+    }
+    for path in ["src", "Cargo.toml", "Cargo.lock"] {
+        println!("cargo:rerun-if-changed={path}");
+    }
+}
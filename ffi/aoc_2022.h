@@ -0,0 +1,43 @@
+/* Generated from src/ffi/mod.rs by `cargo test write_ffi_header -- --ignored`. */
+
+#ifndef AOC_2022_H
+#define AOC_2022_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Returned by aoc_solve. */
+enum aoc_status {
+    /* Solved. */
+    AOC_OK = 0,
+    /* A required pointer was null. */
+    AOC_ERROR_NULL_POINTER = 1,
+    /* The input isn't UTF-8. */
+    AOC_ERROR_INVALID_UTF8 = 2,
+    /* The day isn't enabled in this build of the library. */
+    AOC_ERROR_UNKNOWN_DAY = 3,
+    /* The solver rejected the input. */
+    AOC_ERROR_BAD_INPUT = 4,
+    /* The solver panicked. */
+    AOC_ERROR_PANICKED = 5,
+};
+
+/*
+ * Solve `len` bytes of `input` as `day`. Unless `out_json` is null, it's set to a JSON object
+ * with either the answers and timings or an error message, which must be freed with
+ * aoc_free_json. `input` may be null if `len` is zero. Returns an aoc_status.
+ */
+int aoc_solve(uint8_t day, const uint8_t *input, size_t len, char **out_json);
+
+/* Free a string from aoc_solve. Null is ignored. */
+void aoc_free_json(char *json);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
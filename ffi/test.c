@@ -0,0 +1,45 @@
+/*
+ * Checks the C ABI against the examples from the puzzle descriptions. Run it with `make -C ffi`,
+ * which builds the library with the days it needs.
+ */
+
+#include "aoc_2022.h"
+
+#include <stdio.h>
+#include <string.h>
+
+static int failures = 0;
+
+static void check(uint8_t day, const char *input, int expected_status, const char *expected) {
+    char *json = NULL;
+    int status = aoc_solve(day, (const uint8_t *)input, strlen(input), &json);
+    if (status != expected_status || json == NULL || strstr(json, expected) == NULL) {
+        printf("FAIL day %d: status %d, %s\n", day, status, json ? json : "(null)");
+        failures++;
+    } else {
+        printf("ok   day %d: %s\n", day, json);
+    }
+    aoc_free_json(json);
+}
+
+int main(void) {
+    check(1, "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000", AOC_OK,
+          "\"part1\":\"24000\",\"part2\":\"45000\"");
+    check(2, "A Y\nB X\nC Z", AOC_OK, "\"part1\":\"15\",\"part2\":\"12\"");
+    check(4, "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8", AOC_OK,
+          "\"part1\":\"2\",\"part2\":\"4\"");
+    check(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", AOC_OK, "\"part1\":\"7\",\"part2\":\"19\"");
+
+    check(4, "2-4,x", AOC_ERROR_BAD_INPUT, "\"error\":");
+    check(25, "", AOC_ERROR_UNKNOWN_DAY, "\"error\":");
+    check(1, "\xff", AOC_ERROR_INVALID_UTF8, "\"error\":");
+    /* The ffi-test feature adds a day 0 that always panics. */
+    check(0, "", AOC_ERROR_PANICKED, "\"error\":");
+    if (aoc_solve(1, NULL, 1, NULL) != AOC_ERROR_NULL_POINTER) {
+        printf("FAIL null input\n");
+        failures++;
+    }
+
+    printf("%d failed\n", failures);
+    return failures != 0;
+}
@@ -0,0 +1,111 @@
+use crate::days::DAYS;
+use std::{env, fs, sync::OnceLock, thread};
+
+/// The columns an environment adds to an artifact, in order.
+pub(crate) const COLUMNS: [&str; 8] = [
+    "commit", "dirty", "rustc", "target", "features", "cpu", "cores", "hostname",
+];
+
+/// Where and how a run happened, so a slow run can be told apart from a run on another machine or
+/// compiler. Anything that can't be found out is left empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Environment {
+    /// The commit the binary was built from.
+    pub(crate) commit: String,
+    /// Whether tracked files had uncommitted changes when it was built.
+    pub(crate) dirty: bool,
+    pub(crate) rustc: String,
+    pub(crate) target: String,
+    /// The enabled days, separated by spaces.
+    pub(crate) features: String,
+    pub(crate) cpu: String,
+    pub(crate) cores: usize,
+    pub(crate) hostname: String,
+}
+
+impl Environment {
+    /// The environment of this process, found once since it can't change during a run.
+    pub(crate) fn current() -> &'static Self {
+        static CURRENT: OnceLock<Environment> = OnceLock::new();
+        CURRENT.get_or_init(Self::detect)
+    }
+
+    fn detect() -> Self {
+        let (cpu, cores) = fs::read_to_string("/proc/cpuinfo")
+            .map(|cpuinfo| parse_cpuinfo(&cpuinfo))
+            .unwrap_or_default();
+        Self {
+            // Set by build.rs.
+            commit: env!("AOC_COMMIT").to_owned(),
+            dirty: env!("AOC_DIRTY") == "true",
+            rustc: env!("AOC_RUSTC_VERSION").to_owned(),
+            target: env!("AOC_TARGET").to_owned(),
+            features: DAYS
+                .iter()
+                .map(|day| format!("day{:02}", day.number))
+                .collect::<Vec<_>>()
+                .join(" "),
+            cpu: cpu
+                .or_else(|| env::var("PROCESSOR_IDENTIFIER").ok())
+                .unwrap_or_default(),
+            cores: match cores {
+                0 => thread::available_parallelism().map_or(0, usize::from),
+                cores => cores,
+            },
+            hostname: fs::read_to_string("/proc/sys/kernel/hostname")
+                .ok()
+                .or_else(|| env::var("HOSTNAME").ok())
+                .or_else(|| env::var("COMPUTERNAME").ok())
+                .unwrap_or_default()
+                .trim()
+                .to_owned(),
+        }
+    }
+
+    /// The values for [COLUMNS].
+    pub(crate) fn values(&self) -> [String; 8] {
+        [
+            self.commit.clone(),
+            self.dirty.to_string(),
+            self.rustc.clone(),
+            self.target.clone(),
+            self.features.clone(),
+            self.cpu.clone(),
+            self.cores.to_string(),
+            self.hostname.clone(),
+        ]
+    }
+
+    /// Read an environment back, given a lookup from column name to value. Returns `None` for
+    /// artifacts written before environments were recorded, and for history rows of such runs,
+    /// whose `dirty` is empty.
+    pub(crate) fn from_columns<'a>(column: impl Fn(&str) -> Option<&'a str>) -> Option<Self> {
+        if column("dirty")?.is_empty() {
+            return None;
+        }
+        Some(Self {
+            commit: column("commit")?.to_owned(),
+            dirty: column("dirty")? == "true",
+            rustc: column("rustc")?.to_owned(),
+            target: column("target")?.to_owned(),
+            features: column("features")?.to_owned(),
+            cpu: column("cpu")?.to_owned(),
+            cores: column("cores")?.parse().unwrap_or_default(),
+            hostname: column("hostname")?.to_owned(),
+        })
+    }
+}
+
+/// The CPU model and the number of logical cores.
+pub(crate) fn parse_cpuinfo(cpuinfo: &str) -> (Option<String>, usize) {
+    let field = |line: &str, name: &str| {
+        let (key, value) = line.split_once(':')?;
+        (key.trim() == name).then(|| value.trim().to_owned())
+    };
+    let model = cpuinfo.lines().find_map(|line| field(line, "model name"));
+    let cores = cpuinfo
+        .lines()
+        .filter(|line| field(line, "processor").is_some())
+        .count();
+    (model, cores)
+}
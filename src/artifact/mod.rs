@@ -0,0 +1,424 @@
+use crate::csv;
+use anyhow::{anyhow, Context, Result};
+use chrono::NaiveDateTime;
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::{self, create_dir_all, File},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+mod environment;
+mod query;
+#[cfg(test)]
+mod tests;
+
+pub(crate) use environment::Environment;
+pub(crate) use query::{query, Query};
+
+/// Where artifacts go unless the command line or [FOLDER_VARIABLE] says otherwise.
+pub(crate) const ARTIFACTS_FOLDER: &str = "artifacts";
+
+pub(crate) const FOLDER_VARIABLE: &str = "AOC_ARTIFACTS_DIR";
+
+pub(crate) const USAGE: &str = "[--artifacts-dir DIR] [--no-artifacts]";
+
+pub(crate) const TIMESTAMP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+
+/// The description of the artifact each solved day writes.
+pub(crate) const RUN_DESCRIPTION: &str = "run";
+
+/// The columns of a run artifact, which are followed by the environment's. Older files stop before
+/// `input` or the environment.
+const RUN_COLUMNS: [&str; 6] = [
+    "build_config",
+    "part1",
+    "part2",
+    "duration",
+    "parse_duration",
+    "input",
+];
+
+/// The columns every run artifact has.
+const REQUIRED_RUN_COLUMNS: usize = 5;
+
+/// A day's compacted runs, one row each, with a `timestamp` column before the usual ones. Runs
+/// are kept here instead of in `run_*.csv` files once `compact` has merged them.
+pub(crate) const HISTORY_FILE: &str = "history.csv";
+
+#[cfg(debug_assertions)]
+fn build_config() -> &'static str {
+    "Debug"
+}
+
+#[cfg(not(debug_assertions))]
+fn build_config() -> &'static str {
+    "Release"
+}
+
+/// Where artifacts are read from and written to, and whether to write them at all.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Settings {
+    pub(crate) folder: PathBuf,
+    pub(crate) write: bool,
+}
+
+static SETTINGS: OnceLock<Settings> = OnceLock::new();
+
+impl Settings {
+    /// Take the artifact options from the start of 'args', returning the settings and the rest of
+    /// the arguments. 'variable' is the value of [FOLDER_VARIABLE], which the option overrides.
+    pub(crate) fn from_args(
+        args: &[String],
+        variable: Option<String>,
+    ) -> Result<(Self, &[String])> {
+        let mut settings = Self {
+            folder: PathBuf::from(variable.unwrap_or_else(|| ARTIFACTS_FOLDER.to_owned())),
+            write: true,
+        };
+        let mut args = args;
+        while let Some(arg) = args.first() {
+            if arg == "--no-artifacts" {
+                settings.write = false;
+            } else if arg == "--artifacts-dir" {
+                let folder = args
+                    .get(1)
+                    .ok_or_else(|| anyhow!("--artifacts-dir needs a value"))?;
+                settings.folder = PathBuf::from(folder);
+                args = &args[1..];
+            } else if let Some(folder) = arg.strip_prefix("--artifacts-dir=") {
+                settings.folder = PathBuf::from(folder);
+            } else {
+                break;
+            }
+            args = &args[1..];
+        }
+        Ok((settings, args))
+    }
+
+    /// Use these settings for the rest of the run. Only the first call has any effect.
+    pub(crate) fn apply(self) {
+        let _ = SETTINGS.set(self);
+    }
+
+    /// The settings in use, which come from [FOLDER_VARIABLE] if they haven't been set.
+    pub(crate) fn current() -> &'static Self {
+        SETTINGS.get_or_init(|| Self {
+            folder: PathBuf::from(
+                env::var(FOLDER_VARIABLE).unwrap_or_else(|_| ARTIFACTS_FOLDER.to_owned()),
+            ),
+            write: true,
+        })
+    }
+}
+
+/// The folder artifacts are read from.
+pub(crate) fn folder() -> &'static Path {
+    &Settings::current().folder
+}
+
+/// Write one row of results to a new CSV file, unless artifacts are turned off. Failures are only
+/// warnings, since the results have already been printed.
+pub fn make_artifact(folder: Option<&str>, description: &str, headers: &[&str], row: &[String]) {
+    let settings = Settings::current();
+    if !settings.write {
+        return;
+    }
+    let path = match folder {
+        Some(folder) => settings.folder.join(folder),
+        None => settings.folder.clone(),
+    };
+    if let Err(error) = create_dir_all(&path) {
+        eprintln!(
+            "warning: couldn't create artifact folder {}: {error}",
+            path.display()
+        );
+        return;
+    }
+    let path = path.join(file_name(description, chrono::Local::now().naive_local()));
+    let environment = Environment::current().values();
+    let headers = [&["build_config"], headers, &environment::COLUMNS].concat();
+    let row = [&[build_config().to_owned()], row, &environment].concat();
+    let written = File::create(&path)
+        .and_then(|mut file| write!(&mut file, "{}{}", csv::record(&headers), csv::record(&row)));
+    if let Err(error) = written {
+        eprintln!(
+            "warning: couldn't write artifact {}: {error}",
+            path.display()
+        );
+    }
+}
+
+/// The name of an artifact file, such as `run_2022-12-01_06-00-00.csv`.
+pub(crate) fn file_name(description: &str, timestamp: NaiveDateTime) -> String {
+    format!("{description}_{}.csv", timestamp.format(TIMESTAMP_FORMAT))
+}
+
+/// The description and timestamp in an artifact file's name, if it is one.
+pub(crate) fn parse_file_name(name: &str) -> Option<(&str, NaiveDateTime)> {
+    let stem = name.strip_suffix(".csv")?;
+    // Descriptions can have underscores too, so the timestamp is found from the end.
+    let length = "2022-12-01_06-00-00".len();
+    let split = stem.len().checked_sub(length)?;
+    let description = stem.get(..split)?.strip_suffix('_')?;
+    let timestamp = NaiveDateTime::parse_from_str(stem.get(split..)?, TIMESTAMP_FORMAT).ok()?;
+    Some((description, timestamp))
+}
+
+/// Identifies a puzzle input, so runs on different inputs aren't compared. This is 64-bit FNV-1a,
+/// which is plenty to tell a handful of inputs apart.
+pub(crate) fn fingerprint(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// One run of a day, read back from its `run_*.csv` artifact or the day's [HISTORY_FILE].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RunRecord {
+    pub(crate) day: u8,
+    pub(crate) timestamp: NaiveDateTime,
+    pub(crate) build_config: String,
+    pub(crate) part1: String,
+    pub(crate) part2: String,
+    /// Milliseconds, not counting parsing.
+    pub(crate) duration: f64,
+    pub(crate) parse_duration: f64,
+    /// The input's fingerprint. Runs from before fingerprints were recorded don't have one.
+    pub(crate) input: Option<String>,
+    /// Runs from before environments were recorded don't have one.
+    pub(crate) environment: Option<Environment>,
+}
+
+impl RunRecord {
+    /// Parse a run artifact.
+    pub(crate) fn parse(day: u8, timestamp: NaiveDateTime, contents: &str) -> Result<Self> {
+        match csv::parse(contents).as_deref() {
+            Ok([headers, row]) if headers.len() == row.len() => {
+                let headers = headers.iter().map(String::as_str).collect::<Vec<_>>();
+                let row = row.iter().map(String::as_str).collect::<Vec<_>>();
+                Self::from_columns(day, timestamp, &headers, &row)
+            }
+            _ => Self::parse_unescaped(day, timestamp, contents),
+        }
+    }
+
+    /// Parse an artifact from before rows were escaped, where a multi-line answer such as day 10's
+    /// spreads over several lines. Only part 2 can be like that, so the other fields are split off
+    /// the ends.
+    fn parse_unescaped(day: u8, timestamp: NaiveDateTime, contents: &str) -> Result<Self> {
+        let (headers, row) = contents
+            .split_once('\n')
+            .ok_or_else(|| anyhow!("No data row"))?;
+        let headers = headers.trim_end().split(',').collect::<Vec<_>>();
+        let row = row.trim_end_matches(['\r', '\n']);
+        let bad_row = || anyhow!("Bad row: {row}");
+        if headers.len() < 3 {
+            return Err(anyhow!("Unknown columns: {}", headers.join(",")));
+        }
+        let (build_config, rest) = row.split_once(',').ok_or_else(bad_row)?;
+        let (part1, rest) = rest.split_once(',').ok_or_else(bad_row)?;
+        let mut values = vec![build_config, part1];
+        // Part 2 and everything after it, which comes out last column first.
+        let after = rest.rsplitn(headers.len() - 2, ',').collect::<Vec<_>>();
+        if after.len() != headers.len() - 2 {
+            return Err(bad_row());
+        }
+        values.extend(after.into_iter().rev());
+        Self::from_columns(day, timestamp, &headers, &values)
+    }
+
+    fn from_columns(
+        day: u8,
+        timestamp: NaiveDateTime,
+        headers: &[&str],
+        values: &[&str],
+    ) -> Result<Self> {
+        let known = RUN_COLUMNS.iter().chain(&environment::COLUMNS);
+        if headers.len() < REQUIRED_RUN_COLUMNS
+            || headers.len() > known.clone().count()
+            || !known.zip(headers).all(|(known, header)| known == header)
+        {
+            return Err(anyhow!("Unknown columns: {}", headers.join(",")));
+        }
+        let column = |name: &str| {
+            headers
+                .iter()
+                .position(|header| *header == name)
+                .map(|index| values[index])
+        };
+        let number = |name: &str| -> Result<f64> {
+            let value = column(name).unwrap_or_default();
+            value
+                .parse()
+                .with_context(|| format!("Bad {name}: {value}"))
+        };
+        Ok(Self {
+            day,
+            timestamp,
+            build_config: values[0].to_owned(),
+            part1: values[1].to_owned(),
+            part2: values[2].to_owned(),
+            duration: number("duration")?,
+            parse_duration: number("parse_duration")?,
+            input: column("input")
+                .filter(|input| !input.is_empty())
+                .map(str::to_owned),
+            environment: Environment::from_columns(column),
+        })
+    }
+
+    /// The values for the columns after `timestamp` in a [HISTORY_FILE]. Anything the run didn't
+    /// record is left empty.
+    fn values(&self) -> Vec<String> {
+        let environment = match &self.environment {
+            Some(environment) => environment.values(),
+            None => Default::default(),
+        };
+        [
+            &[
+                self.build_config.clone(),
+                self.part1.clone(),
+                self.part2.clone(),
+                self.duration.to_string(),
+                self.parse_duration.to_string(),
+                self.input.clone().unwrap_or_default(),
+            ][..],
+            &environment,
+        ]
+        .concat()
+    }
+}
+
+/// The contents of a [HISTORY_FILE] holding 'runs'.
+pub(crate) fn format_history(runs: &[&RunRecord]) -> String {
+    let headers = [&["timestamp"], &RUN_COLUMNS[..], &environment::COLUMNS].concat();
+    let mut contents = csv::record(&headers);
+    for run in runs {
+        let timestamp = run.timestamp.format(TIMESTAMP_FORMAT).to_string();
+        contents += &csv::record(&[&[timestamp], &run.values()[..]].concat());
+    }
+    contents
+}
+
+/// Parse a [HISTORY_FILE].
+pub(crate) fn parse_history(day: u8, contents: &str) -> Result<Vec<RunRecord>> {
+    let records = csv::parse(contents)?;
+    let Some((headers, rows)) = records.split_first() else {
+        return Ok(vec![]);
+    };
+    if headers.first().map(String::as_str) != Some("timestamp") {
+        return Err(anyhow!("Unknown columns: {}", headers.join(",")));
+    }
+    let headers = headers[1..].iter().map(String::as_str).collect::<Vec<_>>();
+    rows.iter()
+        .map(|row| {
+            let row = row.iter().map(String::as_str).collect::<Vec<_>>();
+            let (timestamp, values) = row
+                .split_first()
+                .filter(|(_, values)| values.len() == headers.len())
+                .ok_or_else(|| anyhow!("Wrong number of fields"))?;
+            let timestamp = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT)
+                .with_context(|| format!("Bad timestamp: {timestamp}"))?;
+            RunRecord::from_columns(day, timestamp, &headers, values)
+        })
+        .enumerate()
+        .map(|(index, run)| run.with_context(|| format!("Row {}", index + 1)))
+        .collect()
+}
+
+/// Write a [HISTORY_FILE] to a temporary file first, so a failure can't leave it half written.
+pub(crate) fn write_history(path: &Path, runs: &[&RunRecord]) -> Result<()> {
+    let temporary = path.with_extension("csv.tmp");
+    fs::write(&temporary, format_history(runs))
+        .and_then(|_| fs::rename(&temporary, path))
+        .with_context(|| format!("Writing {}", path.display()))
+}
+
+/// Where a run was read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Source {
+    /// Its own `run_*.csv` file.
+    Run(PathBuf),
+    /// A row of the day's [HISTORY_FILE].
+    History,
+}
+
+/// The folder holding a day's artifacts.
+pub(crate) fn day_folder(folder: &Path, day: u8) -> PathBuf {
+    folder.join(format!("day{day:02}"))
+}
+
+/// Every run recorded for a day under 'folder' and where it came from, oldest first. A day with
+/// no artifacts has no runs.
+pub(crate) fn load_day(folder: &Path, day: u8) -> Result<Vec<(RunRecord, Source)>> {
+    let path = day_folder(folder, day);
+    let entries = match fs::read_dir(&path) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(error).with_context(|| format!("Reading {}", path.display())),
+    };
+    let mut runs = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if name == HISTORY_FILE {
+            let contents =
+                fs::read_to_string(&path).with_context(|| format!("Reading {}", path.display()))?;
+            let history = parse_history(day, &contents)
+                .with_context(|| format!("Reading {}", path.display()))?;
+            runs.extend(history.into_iter().map(|run| (run, Source::History)));
+            continue;
+        }
+        let Some((RUN_DESCRIPTION, timestamp)) = parse_file_name(name) else {
+            continue;
+        };
+        let contents =
+            fs::read_to_string(&path).with_context(|| format!("Reading {}", path.display()))?;
+        let run = RunRecord::parse(day, timestamp, &contents)
+            .with_context(|| format!("Reading {}", path.display()))?;
+        runs.push((run, Source::Run(path)));
+    }
+    runs.sort_by_key(|(run, _)| run.timestamp);
+    Ok(runs)
+}
+
+/// Every run recorded for a day under 'folder', oldest first. A day with no artifacts has no runs.
+pub(crate) fn load_runs(folder: &Path, day: u8) -> Result<Vec<RunRecord>> {
+    Ok(load_day(folder, day)?
+        .into_iter()
+        .map(|(run, _)| run)
+        .collect())
+}
+
+/// Every run of every day under 'folder', by day and then oldest first.
+pub(crate) fn load_all_runs(folder: &Path) -> Result<Vec<RunRecord>> {
+    let mut runs = vec![];
+    for day in 1..=25 {
+        runs.extend(load_runs(folder, day)?);
+    }
+    Ok(runs)
+}
+
+/// The latest of 'runs' for each day, optionally only with one build config, in day order.
+pub(crate) fn latest_by_day<'a>(
+    runs: &'a [RunRecord],
+    build_config: Option<&str>,
+) -> Vec<&'a RunRecord> {
+    let mut latest = BTreeMap::<u8, &RunRecord>::new();
+    for run in runs {
+        if build_config.is_some_and(|build_config| run.build_config != build_config) {
+            continue;
+        }
+        let entry = latest.entry(run.day).or_insert(run);
+        if run.timestamp >= entry.timestamp {
+            *entry = run;
+        }
+    }
+    latest.into_values().collect()
+}
@@ -0,0 +1,57 @@
+use super::{load_runs, RunRecord};
+use anyhow::Result;
+use chrono::NaiveDate;
+use std::path::Path;
+
+/// Which runs [query] returns. A field that isn't set matches every run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Query {
+    /// Every day if empty.
+    pub(crate) days: Vec<u8>,
+    /// The first and last dates, inclusive.
+    pub(crate) from: Option<NaiveDate>,
+    pub(crate) to: Option<NaiveDate>,
+    pub(crate) build_config: Option<String>,
+    /// The start of a commit hash, so a short hash works. Runs from before commits were recorded
+    /// never match.
+    pub(crate) commit: Option<String>,
+}
+
+impl Query {
+    pub(crate) fn matches(&self, run: &RunRecord) -> bool {
+        let date = run.timestamp.date();
+        (self.days.is_empty() || self.days.contains(&run.day))
+            && self.from.is_none_or(|from| date >= from)
+            && self.to.is_none_or(|to| date <= to)
+            && self
+                .build_config
+                .as_ref()
+                .is_none_or(|build_config| run.build_config == *build_config)
+            && self.commit.as_ref().is_none_or(|commit| {
+                run.environment
+                    .as_ref()
+                    .is_some_and(|environment| environment.commit.starts_with(commit.as_str()))
+            })
+    }
+}
+
+/// The runs under 'folder' that match 'query', by day and then oldest first.
+pub(crate) fn query(folder: &Path, query: &Query) -> Result<Vec<RunRecord>> {
+    let days = if query.days.is_empty() {
+        (1..=25).collect()
+    } else {
+        let mut days = query.days.clone();
+        days.sort_unstable();
+        days.dedup();
+        days
+    };
+    let mut runs = vec![];
+    for day in days {
+        runs.extend(
+            load_runs(folder, day)?
+                .into_iter()
+                .filter(|run| query.matches(run)),
+        );
+    }
+    Ok(runs)
+}
@@ -0,0 +1,262 @@
+use super::{
+    environment::parse_cpuinfo, file_name, fingerprint, format_history, load_runs, parse_file_name,
+    parse_history, query, Environment, Query, RunRecord, Settings,
+};
+use crate::csv;
+use chrono::NaiveDate;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn parse() {
+    let timestamp = NaiveDate::from_ymd_opt(2022, 12, 13)
+        .unwrap()
+        .and_hms_opt(17, 27, 48)
+        .unwrap();
+    let run = RunRecord::parse(
+        10,
+        timestamp,
+        "build_config,part1,part2,duration,parse_duration\nRelease,12520,#..#\n.##.\n,0.7394,0.0165\n",
+    )
+    .unwrap();
+    assert_eq!(
+        RunRecord {
+            day: 10,
+            timestamp,
+            build_config: "Release".to_owned(),
+            part1: "12520".to_owned(),
+            part2: "#..#\n.##.\n".to_owned(),
+            duration: 0.7394,
+            parse_duration: 0.0165,
+            input: None,
+            environment: None,
+        },
+        run
+    );
+
+    let run = RunRecord::parse(
+        10,
+        timestamp,
+        "build_config,part1,part2,duration,parse_duration,input\nDebug,1,2,\n3,0.5,0.25,0123abcd\n",
+    )
+    .unwrap();
+    assert_eq!("2,\n3", run.part2);
+    assert_eq!((0.5, 0.25), (run.duration, run.parse_duration));
+    assert_eq!(Some("0123abcd"), run.input.as_deref());
+    assert_eq!(None, run.environment);
+
+    assert!(RunRecord::parse(10, timestamp, "a,b\nDebug,1,2").is_err());
+    assert!(RunRecord::parse(
+        10,
+        timestamp,
+        "build_config,part1,part2,duration,parse_duration\nDebug,1,2,fast,0.1"
+    )
+    .is_err());
+}
+
+#[test]
+fn history() {
+    // The artifacts checked into the repository. New runs only ever add to the end.
+    let folder = Path::new(env!("CARGO_MANIFEST_DIR")).join("artifacts");
+    let runs = load_runs(&folder, 1).unwrap();
+    assert_eq!(
+        ["0", "211805", "211805"],
+        [&runs[0].part2, &runs[1].part2, &runs[2].part2]
+    );
+    assert_eq!(
+        ["Debug", "Debug", "Release"],
+        runs[..3]
+            .iter()
+            .map(|run| run.build_config.as_str())
+            .collect::<Vec<_>>()[..]
+    );
+    assert!(runs
+        .windows(2)
+        .all(|pair| pair[0].timestamp <= pair[1].timestamp));
+
+    assert!(load_runs(&folder, 10).unwrap()[1]
+        .part2
+        .contains("\n#....#..#"));
+    assert!(load_runs(&folder, 25).unwrap().is_empty());
+}
+
+#[test]
+fn history_file() {
+    let folder = Path::new(env!("CARGO_MANIFEST_DIR")).join("artifacts");
+    let mut runs = load_runs(&folder, 10).unwrap();
+    runs[0].input = Some("0123abcd".to_owned());
+    runs[1].environment = Some(Environment {
+        commit: "2d163fd".to_owned(),
+        cores: 8,
+        ..Default::default()
+    });
+    let contents = format_history(&runs.iter().collect::<Vec<_>>());
+    assert!(contents.starts_with("timestamp,build_config,"));
+    // Anything not recorded stays that way.
+    assert_eq!(runs, parse_history(10, &contents).unwrap());
+
+    assert!(parse_history(10, "").unwrap().is_empty());
+    assert!(parse_history(10, "build_config,part1\r\nDebug,1\r\n").is_err());
+    assert!(parse_history(
+        10,
+        "timestamp,build_config,part1,part2,duration,parse_duration\r\nyesterday,Debug,1,2,0.5,0.1\r\n"
+    )
+    .is_err());
+}
+
+#[test]
+fn file_names() {
+    let timestamp = NaiveDate::from_ymd_opt(2022, 12, 1)
+        .unwrap()
+        .and_hms_opt(6, 0, 0)
+        .unwrap();
+    assert_eq!("run_2022-12-01_06-00-00.csv", file_name("run", timestamp));
+    assert_eq!(
+        Some(("scale_day01", timestamp)),
+        parse_file_name(&file_name("scale_day01", timestamp))
+    );
+    assert_eq!(None, parse_file_name("history.csv"));
+    assert_eq!(None, parse_file_name("run_2022-12-01_06-00-00.txt"));
+    assert_eq!(None, parse_file_name("run-2022-12-01_06-00-00.csv"));
+    assert_eq!(None, parse_file_name("run_2022-13-01_06-00-00.csv"));
+}
+
+#[test]
+fn queries() {
+    let folder = Path::new(env!("CARGO_MANIFEST_DIR")).join("artifacts");
+    let all = query(&folder, &Query::default()).unwrap();
+    assert!(all.len() > 50);
+    assert!(all.windows(2).all(|pair| pair[0].day <= pair[1].day));
+
+    let december = |day| NaiveDate::from_ymd_opt(2022, 12, day);
+    let runs = query(
+        &folder,
+        &Query {
+            days: vec![2, 1, 2],
+            from: december(1),
+            to: december(2),
+            build_config: Some("Release".to_owned()),
+            commit: None,
+        },
+    )
+    .unwrap();
+    assert!(!runs.is_empty());
+    assert!(runs.iter().all(|run| [1, 2].contains(&run.day)
+        && run.build_config == "Release"
+        && (december(1).unwrap()..=december(2).unwrap()).contains(&run.timestamp.date())));
+    assert_eq!(
+        runs.len(),
+        all.iter()
+            .filter(|run| [1, 2].contains(&run.day))
+            .filter(|run| run.build_config == "Release")
+            .filter(|run| run.timestamp.date() <= december(2).unwrap())
+            .filter(|run| run.timestamp.date() >= december(1).unwrap())
+            .count()
+    );
+
+    let mut run = all[0].clone();
+    let commit = Query {
+        commit: Some("2d163".to_owned()),
+        ..Default::default()
+    };
+    // Runs from before commits were recorded never match one.
+    assert!(!commit.matches(&run));
+    run.environment = Some(Environment {
+        commit: "2d163fd".to_owned(),
+        ..Default::default()
+    });
+    assert!(commit.matches(&run));
+    run.environment.as_mut().unwrap().commit = "9e2a".to_owned();
+    assert!(!commit.matches(&run));
+}
+
+#[test]
+fn fingerprints() {
+    // The published FNV-1a test vectors.
+    assert_eq!("cbf29ce484222325", fingerprint(""));
+    assert_eq!("af63dc4c8601ec8c", fingerprint("a"));
+    assert_ne!(fingerprint("1\n2"), fingerprint("1\n3"));
+}
+
+#[test]
+fn environment() {
+    let environment = Environment {
+        commit: "2d163fd".to_owned(),
+        dirty: true,
+        rustc: "rustc 1.70.0 (90c541806 2023-05-31)".to_owned(),
+        target: "x86_64-pc-windows-msvc".to_owned(),
+        features: "day01 day10".to_owned(),
+        cpu: "AMD Ryzen 7, 8 cores".to_owned(),
+        cores: 16,
+        hostname: "desk".to_owned(),
+    };
+    let headers = [
+        &[
+            "build_config",
+            "part1",
+            "part2",
+            "duration",
+            "parse_duration",
+            "input",
+        ][..],
+        &super::environment::COLUMNS,
+    ]
+    .concat();
+    let row = [
+        &[
+            "Release".to_owned(),
+            "1,5".to_owned(),
+            "#.\n.#\n".to_owned(),
+            "0.5".to_owned(),
+            "0.25".to_owned(),
+            "0123abcd".to_owned(),
+        ][..],
+        &environment.values(),
+    ]
+    .concat();
+    let contents = csv::record(&headers) + &csv::record(&row);
+    let run = RunRecord::parse(10, Default::default(), &contents).unwrap();
+    assert_eq!("1,5", run.part1);
+    assert_eq!("#.\n.#\n", run.part2);
+    assert_eq!(Some("0123abcd"), run.input.as_deref());
+    assert_eq!(Some(environment), run.environment);
+
+    let cpuinfo = "processor\t: 0\nmodel name\t: Intel(R) Xeon(R) CPU @ 2.20GHz\n\nprocessor\t: 1\nmodel name\t: Intel(R) Xeon(R) CPU @ 2.20GHz\n";
+    assert_eq!(
+        (Some("Intel(R) Xeon(R) CPU @ 2.20GHz".to_owned()), 2),
+        parse_cpuinfo(cpuinfo)
+    );
+    assert_eq!((None, 0), parse_cpuinfo(""));
+}
+
+#[test]
+fn settings() {
+    let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+    let all = args(&[
+        "--no-artifacts",
+        "--artifacts-dir",
+        "out",
+        "compare",
+        "--no-artifacts",
+    ]);
+    let (settings, rest) = Settings::from_args(&all, None).unwrap();
+    assert_eq!(
+        Settings {
+            folder: PathBuf::from("out"),
+            write: false
+        },
+        settings
+    );
+    // Options after the command belong to the command.
+    assert_eq!(args(&["compare", "--no-artifacts"]), rest);
+
+    let (settings, rest) = Settings::from_args(&[], Some("/tmp/aoc".to_owned())).unwrap();
+    assert_eq!(PathBuf::from("/tmp/aoc"), settings.folder);
+    assert!(settings.write && rest.is_empty());
+
+    let all = args(&["--artifacts-dir=flag"]);
+    let (settings, _) = Settings::from_args(&all, Some("variable".to_owned())).unwrap();
+    assert_eq!(PathBuf::from("flag"), settings.folder);
+
+    assert!(Settings::from_args(&args(&["--artifacts-dir"]), None).is_err());
+}
@@ -0,0 +1,88 @@
+use anyhow::{anyhow, Context, Result};
+use std::{collections::HashMap, str::FromStr};
+
+/// Command line arguments for a command: positional arguments plus `--name value`, `--name=value`
+/// and `--switch` options.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Args {
+    positional: Vec<String>,
+    values: HashMap<String, String>,
+    switches: Vec<String>,
+}
+
+impl Args {
+    /// Parse 'args', accepting only the named value options and switches.
+    pub(crate) fn parse(
+        args: &[String],
+        value_options: &[&str],
+        switches: &[&str],
+    ) -> Result<Self> {
+        let mut parsed = Self::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let Some(option) = arg.strip_prefix("--") else {
+                parsed.positional.push(arg.clone());
+                continue;
+            };
+            let (name, inline_value) = match option.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_owned())),
+                None => (option, None),
+            };
+            if value_options.contains(&name) {
+                let value = match inline_value {
+                    Some(value) => value,
+                    None => args
+                        .next()
+                        .cloned()
+                        .ok_or_else(|| anyhow!("--{name} needs a value"))?,
+                };
+                parsed.values.insert(name.to_owned(), value);
+            } else if switches.contains(&name) && inline_value.is_none() {
+                parsed.switches.push(name.to_owned());
+            } else {
+                return Err(anyhow!("Unknown option: {arg}"));
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub(crate) fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    pub(crate) fn switch(&self, name: &str) -> bool {
+        self.switches.iter().any(|switch| switch == name)
+    }
+
+    pub(crate) fn value(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    pub(crate) fn parsed<T>(&self, name: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.value(name)
+            .map(|value| value.parse::<T>())
+            .transpose()
+            .with_context(|| format!("Bad value for --{name}"))
+    }
+
+    /// A comma-separated list, e.g. `--sizes 1,10,100`.
+    pub(crate) fn list<T>(&self, name: &str) -> Result<Option<Vec<T>>>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        self.value(name)
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|item| item.trim().parse::<T>())
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()
+            .with_context(|| format!("Bad value for --{name}"))
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    artifact::{self, fingerprint, load_runs, RunRecord},
+    cli::Args,
+    days::{parse_day_number, DAYS},
+};
+use anyhow::{anyhow, Result};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const USAGE: &str =
+    "compare [DAY...] [--last N] [--threshold PERCENT] [--build-config Debug|Release]";
+
+const DEFAULT_LAST: usize = 5;
+
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// How a day's latest run compares with earlier runs with the same build config and input.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Report<'a> {
+    pub(crate) latest: &'a RunRecord,
+    pub(crate) previous: Option<&'a RunRecord>,
+    /// The fastest earlier run.
+    pub(crate) best: Option<&'a RunRecord>,
+    /// The median duration of up to 'last' earlier runs, and how many there were.
+    pub(crate) median: Option<(f64, usize)>,
+}
+
+pub(crate) fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable_by(f64::total_cmp);
+    let middle = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    })
+}
+
+/// Compare the latest of 'runs' with the build config, which are oldest first, against the runs
+/// before it on the same input. Runs recorded before inputs were fingerprinted are taken to be on
+/// the built-in input, whose fingerprint is 'builtin'.
+pub(crate) fn report<'a>(
+    runs: &'a [RunRecord],
+    build_config: &str,
+    builtin: Option<&str>,
+    last: usize,
+) -> Option<Report<'a>> {
+    let input = |run: &RunRecord| run.input.clone().or_else(|| builtin.map(str::to_owned));
+    let mut runs = runs.iter().filter(|run| run.build_config == build_config);
+    let latest = runs.next_back()?;
+    let earlier = runs
+        .filter(|run| input(run) == input(latest))
+        .collect::<Vec<_>>();
+    let mut recent = earlier
+        .iter()
+        .rev()
+        .take(last)
+        .map(|run| run.duration)
+        .collect::<Vec<_>>();
+    Some(Report {
+        latest,
+        previous: earlier.last().copied(),
+        best: earlier
+            .iter()
+            .copied()
+            .min_by(|a, b| a.duration.total_cmp(&b.duration)),
+        median: median(&mut recent).map(|median| (median, recent.len())),
+    })
+}
+
+/// The change from 'reference' to 'duration' as a percentage, if there's a reference to compare
+/// with: runs too quick to time record 0 ms.
+pub(crate) fn change(duration: f64, reference: f64) -> Option<f64> {
+    (reference > 0.0).then(|| (duration - reference) / reference * 100.0)
+}
+
+/// Print a day's report, returning whether it has a regression.
+fn print_report(day: u8, report: &Report, threshold: f64) -> bool {
+    let mut regressed = false;
+    let mut line = |label: &str, duration: f64, flag_regression: bool| {
+        let difference = change(report.latest.duration, duration);
+        let flag = if flag_regression && difference.is_some_and(|difference| difference > threshold)
+        {
+            regressed = true;
+            "  REGRESSION"
+        } else {
+            ""
+        };
+        let difference = difference.map_or_else(
+            || format!("{:>8}", "n/a"),
+            |difference| format!("{difference:+7.1}%"),
+        );
+        println!("  {label:<22} {duration:>12.4} ms  {difference}{flag}");
+    };
+
+    println!(
+        "day{day:02} ({}), latest run {}: {:.4} ms",
+        report.latest.build_config, report.latest.timestamp, report.latest.duration
+    );
+    if let Some(previous) = report.previous {
+        line(
+            &format!("previous ({})", previous.timestamp.date()),
+            previous.duration,
+            true,
+        );
+    }
+    if let Some(best) = report.best {
+        // The best run is a target rather than a baseline, so it isn't flagged.
+        line(
+            &format!("best ({})", best.timestamp.date()),
+            best.duration,
+            false,
+        );
+    }
+    if let Some((median, count)) = report.median {
+        line(&format!("median of last {count}"), median, true);
+    }
+    if report.previous.is_none() {
+        println!("  no earlier runs to compare with");
+    }
+    regressed
+}
+
+pub(crate) fn run(args: &[String]) -> Result<()> {
+    let args = Args::parse(args, &["last", "threshold", "build-config"], &[])?;
+    let last = args.parsed::<usize>("last")?.unwrap_or(DEFAULT_LAST);
+    let threshold = args
+        .parsed::<f64>("threshold")?
+        .unwrap_or(DEFAULT_THRESHOLD);
+    let days = if args.positional().is_empty() {
+        (1..=25).collect()
+    } else {
+        args.positional()
+            .iter()
+            .map(|day| parse_day_number(day))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let mut regressions = vec![];
+    for day in days {
+        let runs = load_runs(artifact::folder(), day)?;
+        let Some(build_config) = args
+            .value("build-config")
+            .or_else(|| runs.last().map(|run| run.build_config.as_str()))
+        else {
+            continue;
+        };
+        let builtin = DAYS
+            .iter()
+            .find(|found| found.number == day)
+            .map(|found| fingerprint(found.input));
+        match report(&runs, build_config, builtin.as_deref(), last) {
+            Some(report) => {
+                if print_report(day, &report, threshold) {
+                    regressions.push(format!("day{day:02}"));
+                }
+            }
+            None => println!("day{day:02}: no {build_config} runs"),
+        }
+        println!();
+    }
+
+    if regressions.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "Timing regressions over {threshold}%: {}",
+            regressions.join(", ")
+        ))
+    }
+}
@@ -0,0 +1,81 @@
+use super::{change, median, report};
+use crate::artifact::RunRecord;
+use chrono::NaiveDate;
+
+fn run(day: u32, build_config: &str, duration: f64) -> RunRecord {
+    RunRecord {
+        day: 1,
+        timestamp: NaiveDate::from_ymd_opt(2022, 12, day)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        build_config: build_config.to_owned(),
+        part1: "1".to_owned(),
+        part2: "2".to_owned(),
+        duration,
+        parse_duration: 0.1,
+        input: None,
+        environment: None,
+    }
+}
+
+#[test]
+fn medians() {
+    assert_eq!(None, median(&mut []));
+    assert_eq!(Some(2.0), median(&mut [3.0, 1.0, 2.0]));
+    assert_eq!(Some(2.5), median(&mut [4.0, 1.0, 3.0, 2.0]));
+}
+
+#[test]
+fn reports() {
+    let runs = [
+        run(1, "Release", 1.0),
+        run(2, "Debug", 9.0),
+        run(3, "Release", 4.0),
+        run(4, "Release", 2.0),
+        run(5, "Release", 3.0),
+        run(6, "Debug", 8.0),
+        run(7, "Release", 3.3),
+    ];
+    let report = report(&runs, "Release", None, 3).unwrap();
+    assert_eq!(&runs[6], report.latest);
+    assert_eq!(Some(&runs[4]), report.previous);
+    assert_eq!(Some(&runs[0]), report.best);
+    assert_eq!(Some((3.0, 3)), report.median);
+    assert!((change(report.latest.duration, 3.0).unwrap() - 10.0).abs() < 1e-9);
+
+    let report = super::report(&runs[..2], "Debug", None, 3).unwrap();
+    assert_eq!(None, report.previous);
+    assert_eq!(None, report.median);
+    assert!(super::report(&runs, "Profile", None, 3).is_none());
+}
+
+#[test]
+fn zero_references() {
+    assert_eq!(None, change(1.0, 0.0));
+    assert_eq!(Some(-100.0), change(0.0, 1.0));
+}
+
+#[test]
+fn reports_on_the_same_input() {
+    let on = |input: Option<&str>, run: RunRecord| RunRecord {
+        input: input.map(str::to_owned),
+        ..run
+    };
+    let runs = [
+        on(None, run(1, "Release", 1.0)),
+        on(Some("other"), run(2, "Release", 0.5)),
+        on(Some("builtin"), run(3, "Release", 2.0)),
+        on(Some("other"), run(4, "Release", 0.7)),
+        on(Some("builtin"), run(5, "Release", 3.0)),
+    ];
+    let report = report(&runs, "Release", Some("builtin"), 5).unwrap();
+    assert_eq!(&runs[4], report.latest);
+    assert_eq!(Some(&runs[2]), report.previous);
+    assert_eq!(Some(&runs[0]), report.best);
+    assert_eq!(Some((1.5, 2)), report.median);
+
+    let report = super::report(&runs[..4], "Release", Some("builtin"), 5).unwrap();
+    assert_eq!(Some(&runs[1]), report.previous);
+    assert_eq!(Some((0.5, 1)), report.median);
+}
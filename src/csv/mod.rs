@@ -0,0 +1,99 @@
+//! Comma-separated values as described by RFC 4180. Fields are quoted when they contain a comma,
+//! a quote or a line break, so any string round-trips exactly.
+
+use anyhow::{anyhow, Result};
+
+#[cfg(test)]
+mod tests;
+
+/// Records end with CRLF, as the RFC says. The reader accepts LF too.
+const LINE_END: &str = "\r\n";
+
+fn needs_quotes(field: &str) -> bool {
+    field.contains([',', '"', '\r', '\n'])
+}
+
+/// Append one field, quoting it if it needs it.
+fn write_field(out: &mut String, field: &str) {
+    if needs_quotes(field) {
+        out.push('"');
+        out.push_str(&field.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(field);
+    }
+}
+
+/// One record, including its line ending.
+pub(crate) fn record<S: AsRef<str>>(fields: &[S]) -> String {
+    let mut out = String::new();
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        write_field(&mut out, field.as_ref());
+    }
+    out.push_str(LINE_END);
+    out
+}
+
+/// Parse every record. A line ending after the last record is optional.
+pub(crate) fn parse(text: &str) -> Result<Vec<Vec<String>>> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    // Whether the current field was quoted, so an empty quoted field isn't mistaken for a new one.
+    let mut quoted = false;
+    // Whether anything has been read since the last record ended.
+    let mut started = false;
+    let mut line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        started = true;
+        match c {
+            '"' if field.is_empty() && !quoted => {
+                quoted = true;
+                let start = line;
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            field.push(c);
+                        }
+                        None => return Err(anyhow!("Line {start}: unterminated quoted field")),
+                    }
+                }
+                if !matches!(chars.peek(), None | Some(',' | '\r' | '\n')) {
+                    return Err(anyhow!("Line {line}: text after a quoted field"));
+                }
+            }
+            '"' => return Err(anyhow!("Line {line}: quote inside an unquoted field")),
+            ',' => {
+                record.push(std::mem::take(&mut field));
+                quoted = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+                quoted = false;
+                started = false;
+                line += 1;
+            }
+            c => field.push(c),
+        }
+    }
+    if started {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
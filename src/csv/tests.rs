@@ -0,0 +1,41 @@
+use super::{parse, record};
+
+#[test]
+fn round_trip() {
+    let fields = [
+        "Release",
+        "",
+        "a,b",
+        "say \"hi\"",
+        "####.#..\n#....#..\n",
+        "crlf\r\nline",
+        "\"",
+        " spaced ",
+    ];
+    let text = record(&fields) + &record(&["x"]);
+    assert_eq!(vec![fields.to_vec(), vec!["x"]], parse(&text).unwrap());
+    assert_eq!("a,,\"b,c\",\"\"\"\"\r\n", record(&["a", "", "b,c", "\""]));
+}
+
+#[test]
+fn reading() {
+    assert_eq!(
+        vec![vec!["a", "b"], vec!["1", ""]],
+        parse("a,b\n1,").unwrap()
+    );
+    assert_eq!(vec![vec!["", ""]], parse("\"\",\"\"\r\n").unwrap());
+    assert!(parse("").unwrap().is_empty());
+
+    assert_eq!(
+        "Line 2: unterminated quoted field",
+        parse("a\n\"b\nc").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Line 1: quote inside an unquoted field",
+        parse("a\"b").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "Line 1: text after a quoted field",
+        parse("\"a\"b").unwrap_err().to_string()
+    );
+}
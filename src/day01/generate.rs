@@ -0,0 +1,16 @@
+use crate::generate::Rng;
+
+/// Roughly 250 elves per unit of scale.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let elf_count = 250 * scale;
+    let mut input = String::new();
+    for elf in 0..elf_count {
+        if elf != 0 {
+            input.push('\n');
+        }
+        for _ in 0..rng.range(1, 16) {
+            input.push_str(&format!("{}\n", rng.range(1000, 60_000)));
+        }
+    }
+    input
+}
@@ -1,4 +1,4 @@
-pub(super) const INPUT: &str = "3264
+pub(crate) const INPUT: &str = "3264
 4043
 2537
 3319
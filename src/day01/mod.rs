@@ -1,42 +1,186 @@
-use self::input::INPUT;
-use anyhow::Result;
-use std::time::{Duration, Instant};
+use self::{input::INPUT, report::Report};
+use crate::{
+    cli::Args,
+    csv,
+    days::Solution,
+    scanner::{ParseError, Scanner},
+};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    time::Duration,
+};
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
+
+mod report;
 
 #[cfg(test)]
 mod tests;
 
-fn parse(input: &str) -> Result<Vec<Vec<usize>>> {
-    let mut inventory_lists = vec![];
-    let mut inventory = vec![];
-    for line in input.lines() {
-        if line.is_empty() {
-            inventory_lists.push(inventory);
-            inventory = vec![];
+pub(crate) const USAGE: &str = "elves [FILE] [--top N] [--report | --csv]";
+
+/// One elf's inventory, as it streams past.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Elf {
+    /// Counting from 0, in input order.
+    pub(crate) index: usize,
+    pub(crate) items: usize,
+    pub(crate) total: usize,
+}
+
+/// Read an inventory an elf at a time, calling 'visit' with each. Only the current line is held,
+/// so an inventory of any size takes constant memory.
+pub(crate) fn for_each_elf(mut reader: impl BufRead, mut visit: impl FnMut(Elf)) -> Result<()> {
+    let mut elf = Elf {
+        index: 0,
+        items: 0,
+        total: 0,
+    };
+    let mut line = String::new();
+    let mut number = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        number += 1;
+        let mut scanner = Scanner::new(&line).starting_at_line(number);
+        if scanner.is_at_line_end() {
+            visit(elf);
+            elf = Elf {
+                index: elf.index + 1,
+                items: 0,
+                total: 0,
+            };
         } else {
-            inventory.push(line.parse::<usize>()?);
+            let calories = scanner
+                .unsigned::<usize>()
+                .and_then(|calories| scanner.end_of_line().map(|_| calories))
+                .map_err(|error| ParseError::at_line(1, &line, error))?;
+            elf.items += 1;
+            elf.total += calories;
         }
     }
-    inventory_lists.push(inventory);
-    Ok(inventory_lists)
+    if elf.items > 0 {
+        visit(elf);
+    }
+    Ok(())
+}
+
+/// The 'k' largest values pushed, held in a min-heap so that memory stays bounded by 'k' however
+/// many are pushed.
+#[derive(Clone, Debug)]
+pub(crate) struct TopK<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub(crate) fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k),
+        }
+    }
+
+    pub(crate) fn push(&mut self, value: T) {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(value));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if value > smallest.0 {
+                *smallest = Reverse(value);
+            }
+        }
+    }
+
+    /// The values kept, largest first.
+    pub(crate) fn into_sorted_vec(self) -> Vec<T> {
+        // Sorting the reversed values ascending puts the largest first.
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(value)| value)
+            .collect()
+    }
+}
+
+/// The 'k' largest elf totals in an inventory, largest first.
+pub(crate) fn top_totals(reader: impl BufRead, k: usize) -> Result<Vec<usize>> {
+    let mut top = TopK::new(k);
+    for_each_elf(reader, |elf| top.push(elf.total))?;
+    Ok(top.into_sorted_vec())
 }
 
 fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
-    let timer = Instant::now();
-    let inventory_lists = parse(input)?;
-    let parse_duration = timer.elapsed();
-    let mut inventory_sums = inventory_lists
-        .iter()
-        .map(|inventory| inventory.iter().sum::<usize>())
-        .collect::<Vec<_>>();
-    inventory_sums.sort();
-    let largest_inventory = inventory_sums.iter().rev().take(1).sum::<usize>();
-    let largest_three = inventory_sums.iter().rev().take(3).sum::<usize>();
-    Ok((largest_inventory, largest_three, parse_duration))
+    // Streaming parses and solves in one pass. It all counts as solving, since counting it as
+    // parsing would record day 1 as taking no time at all.
+    let top = top_totals(input.as_bytes(), 3)?;
+    let largest_inventory = top.first().copied().unwrap_or_default();
+    let largest_three = top.iter().sum::<usize>();
+    Ok((largest_inventory, largest_three, Duration::ZERO))
 }
 
 pub(crate) fn solve() -> (usize, usize, Duration) {
     let (part1, part2, parse_duration) = solve_for(INPUT).unwrap();
     (part1, part2, parse_duration)
 }
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}
+
+/// Stream an inventory from a file, or from standard input without one.
+fn open(args: &Args) -> Result<Box<dyn BufRead>> {
+    match args.positional() {
+        [] => Ok(Box::new(io::stdin().lock())),
+        [file] => {
+            let reader = File::open(file).with_context(|| format!("Opening {file}"))?;
+            Ok(Box::new(BufReader::new(reader)))
+        }
+        files => Err(anyhow!("Expected one file, not {files:?}")),
+    }
+}
+
+/// Every elf's item count and total as CSV, a line at a time.
+fn write_csv(reader: impl BufRead, out: impl Write) -> Result<()> {
+    let mut out = BufWriter::new(out);
+    out.write_all(csv::record(&["elf", "items", "calories"]).as_bytes())?;
+    let mut written = Ok(());
+    for_each_elf(reader, |elf| {
+        if written.is_ok() {
+            let fields = [elf.index, elf.items, elf.total].map(|field| field.to_string());
+            written = out.write_all(csv::record(&fields).as_bytes());
+        }
+    })?;
+    written?;
+    out.flush()?;
+    Ok(())
+}
+
+/// The command line for inventories too big to solve as a string.
+pub(crate) fn run(args: &[String]) -> Result<()> {
+    let args = Args::parse(args, &["top"], &["report", "csv"])?;
+    let k = args.parsed::<usize>("top")?.unwrap_or(3);
+    match (args.switch("report"), args.switch("csv")) {
+        (true, true) => Err(anyhow!("Choose one of --report and --csv")),
+        (true, false) => {
+            print!("{}", Report::read(open(&args)?, k)?);
+            Ok(())
+        }
+        (false, true) => write_csv(open(&args)?, io::stdout().lock()),
+        (false, false) => {
+            let top = top_totals(open(&args)?, k)?;
+            for (rank, total) in top.iter().enumerate() {
+                println!("{:>4}  {total}", rank + 1);
+            }
+            println!(" sum  {}", top.iter().sum::<usize>());
+            Ok(())
+        }
+    }
+}
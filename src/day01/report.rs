@@ -0,0 +1,244 @@
+//! A fuller look at an inventory than the puzzle asks for: how items and calories spread across
+//! the elves, and which elves carry the most.
+
+use super::{for_each_elf, Elf, TopK};
+use anyhow::{anyhow, Result};
+use std::{cmp::Reverse, fmt, io::BufRead};
+
+/// The percentiles reported, besides the minimum and maximum.
+const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+
+/// How many bins the histogram of totals has, at most.
+const BINS: usize = 10;
+
+/// How many characters the longest histogram bar takes.
+const BAR_WIDTH: usize = 40;
+
+/// How some count is spread across the elves.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Distribution {
+    pub(crate) sum: usize,
+    pub(crate) mean: f64,
+    pub(crate) min: usize,
+    pub(crate) max: usize,
+    /// Each of [PERCENTILES] with its value, found by nearest rank so that every value is one an
+    /// elf actually has.
+    pub(crate) percentiles: Vec<(u8, usize)>,
+}
+
+impl Distribution {
+    /// Sorts 'values' in place. `None` when there aren't any.
+    pub(crate) fn of(values: &mut [usize]) -> Option<Self> {
+        values.sort_unstable();
+        let (&min, &max) = (values.first()?, values.last()?);
+        let sum = values.iter().sum::<usize>();
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&percentile| {
+                let rank = (percentile as usize * values.len()).div_ceil(100);
+                (percentile, values[rank.max(1) - 1])
+            })
+            .collect();
+        Some(Self {
+            sum,
+            mean: sum as f64 / values.len() as f64,
+            min,
+            max,
+            percentiles,
+        })
+    }
+}
+
+/// The elves whose totals fall from `start` up to but not including `end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Bin {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) elves: usize,
+}
+
+/// Split the range of the sorted 'totals' into equal bins and count the elves in each.
+fn histogram(totals: &[usize]) -> Vec<Bin> {
+    let (Some(&min), Some(&max)) = (totals.first(), totals.last()) else {
+        return vec![];
+    };
+    let width = (max - min) / BINS + 1;
+    let mut bins = (0..=(max - min) / width)
+        .map(|bin| Bin {
+            start: min + bin * width,
+            end: min + (bin + 1) * width,
+            elves: 0,
+        })
+        .collect::<Vec<_>>();
+    for total in totals {
+        bins[(total - min) / width].elves += 1;
+    }
+    bins
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Report {
+    pub(crate) elves: usize,
+    pub(crate) items: Distribution,
+    pub(crate) totals: Distribution,
+    pub(crate) histogram: Vec<Bin>,
+    /// The 'k' elves with the largest totals, largest first. Ties go to the earlier elf.
+    pub(crate) top: Vec<Elf>,
+}
+
+impl Report {
+    /// Unlike the puzzle answers, percentiles need every elf's counts, so this holds two numbers
+    /// an elf rather than a constant amount.
+    pub(crate) fn read(reader: impl BufRead, k: usize) -> Result<Self> {
+        let mut items = vec![];
+        let mut totals = vec![];
+        let mut top = TopK::new(k);
+        for_each_elf(reader, |elf| {
+            items.push(elf.items);
+            totals.push(elf.total);
+            top.push((elf.total, Reverse(elf.index)));
+        })?;
+        let top = top
+            .into_sorted_vec()
+            .into_iter()
+            .map(|(total, Reverse(index))| Elf {
+                index,
+                items: items[index],
+                total,
+            })
+            .collect();
+
+        let no_elves = || anyhow!("There are no elves");
+        Ok(Self {
+            elves: totals.len(),
+            items: Distribution::of(&mut items).ok_or_else(no_elves)?,
+            totals: Distribution::of(&mut totals).ok_or_else(no_elves)?,
+            // Sorted for the distribution now, as the histogram needs.
+            histogram: histogram(&totals),
+            top,
+        })
+    }
+}
+
+/// Lay out 'rows' in columns two spaces apart, with the numbers to the right. A 'labelled' table
+/// has names in its first column, which go to the left.
+fn table(rows: &[Vec<String>], labelled: bool) -> String {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let widths = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    rows.iter()
+        .map(|row| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .enumerate()
+                .map(|(index, (cell, &width))| {
+                    if labelled && index == 0 {
+                        format!("{cell:<width$}")
+                    } else {
+                        format!("{cell:>width$}")
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            line.trim_end().to_owned() + "\n"
+        })
+        .collect()
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let row = |name: &str, items: String, totals: String| vec![name.to_owned(), items, totals];
+        let mut rows = vec![
+            vec!["elves".to_owned(), self.elves.to_string()],
+            vec![],
+            row("", "items".to_owned(), "calories".to_owned()),
+            row(
+                "sum",
+                self.items.sum.to_string(),
+                self.totals.sum.to_string(),
+            ),
+            row(
+                "mean",
+                format!("{:.1}", self.items.mean),
+                format!("{:.1}", self.totals.mean),
+            ),
+            row(
+                "min",
+                self.items.min.to_string(),
+                self.totals.min.to_string(),
+            ),
+        ];
+        for (&(percentile, items), &(_, total)) in
+            self.items.percentiles.iter().zip(&self.totals.percentiles)
+        {
+            let name = match percentile {
+                50 => "median".to_owned(),
+                _ => format!("p{percentile}"),
+            };
+            rows.push(row(&name, items.to_string(), total.to_string()));
+        }
+        rows.push(row(
+            "max",
+            self.items.max.to_string(),
+            self.totals.max.to_string(),
+        ));
+        writeln!(f, "{}", table(&rows, true))?;
+
+        let most = self
+            .histogram
+            .iter()
+            .map(|bin| bin.elves)
+            .max()
+            .unwrap_or(1);
+        let labels = self
+            .histogram
+            .iter()
+            .map(|bin| format!("{}-{}", bin.start, bin.end - 1))
+            .collect::<Vec<_>>();
+        let width = labels.iter().map(String::len).max().unwrap_or_default();
+        let counts = self
+            .histogram
+            .iter()
+            .map(|bin| bin.elves.to_string().len())
+            .max()
+            .unwrap_or_default();
+        writeln!(f, "calories per elf")?;
+        for (bin, label) in self.histogram.iter().zip(labels) {
+            // Any elves at all get some bar.
+            let bar = (bin.elves * BAR_WIDTH).div_ceil(most);
+            writeln!(
+                f,
+                "{label:>width$}  {:>counts$}  {}",
+                bin.elves,
+                "#".repeat(bar)
+            )?;
+        }
+
+        let mut rows = vec![["rank", "elf", "items", "calories"]
+            .map(str::to_owned)
+            .to_vec()];
+        for (rank, elf) in self.top.iter().enumerate() {
+            rows.push(
+                [rank + 1, elf.index, elf.items, elf.total]
+                    .map(|cell| cell.to_string())
+                    .to_vec(),
+            );
+        }
+        let sum = self.top.iter().map(|elf| elf.total).sum::<usize>();
+        rows.push(vec![
+            "sum".to_owned(),
+            String::new(),
+            String::new(),
+            sum.to_string(),
+        ]);
+        write!(f, "\n{}", table(&rows, false))
+    }
+}
@@ -1,4 +1,8 @@
-use super::{input, solve_for};
+use super::{
+    for_each_elf, input,
+    report::{Bin, Report},
+    solve_for, top_totals, write_csv, Elf, TopK,
+};
 
 const INPUT: &str = "1000
 2000
@@ -26,3 +30,118 @@ fn part2() {
     assert_eq!(45000, solve_for(INPUT).unwrap().1);
     assert_eq!(211805, solve_for(input::INPUT).unwrap().1);
 }
+
+#[test]
+fn top_k() {
+    let mut top = TopK::new(3);
+    for value in [5, 1, 9, 7, 3, 9] {
+        top.push(value);
+    }
+    assert_eq!(vec![9, 9, 7], top.into_sorted_vec());
+
+    let mut top = TopK::new(0);
+    top.push(1);
+    assert!(top.into_sorted_vec().is_empty());
+
+    assert_eq!(
+        vec![24000, 11000, 10000, 6000, 4000],
+        top_totals(INPUT.as_bytes(), 10).unwrap()
+    );
+    assert_eq!(vec![24000], top_totals(INPUT.as_bytes(), 1).unwrap());
+}
+
+#[test]
+fn streaming() {
+    let mut elves = vec![];
+    for_each_elf("1\r\n2\r\n\r\n\r\n3".as_bytes(), |elf| elves.push(elf)).unwrap();
+    assert_eq!(
+        vec![
+            Elf {
+                index: 0,
+                items: 2,
+                total: 3
+            },
+            Elf {
+                index: 1,
+                items: 0,
+                total: 0
+            },
+            Elf {
+                index: 2,
+                items: 1,
+                total: 3
+            },
+        ],
+        elves
+    );
+
+    let error = top_totals("1000\n\n2000\n20x0\n".as_bytes(), 3).unwrap_err();
+    assert_eq!(
+        "day 1, line 4, column 3: expected end of line\n  20x0\n    ^",
+        error.to_string()
+    );
+}
+
+#[test]
+fn report() {
+    let report = Report::read(INPUT.as_bytes(), 3).unwrap();
+    assert_eq!(5, report.elves);
+    assert_eq!(
+        (10, 2.0, 1, 3),
+        (
+            report.items.sum,
+            report.items.mean,
+            report.items.min,
+            report.items.max
+        )
+    );
+    assert_eq!(
+        vec![
+            (10, 4000),
+            (25, 6000),
+            (50, 10000),
+            (75, 11000),
+            (90, 24000),
+            (99, 24000)
+        ],
+        report.totals.percentiles
+    );
+    assert_eq!(11000.0, report.totals.mean);
+    assert_eq!(
+        Bin {
+            start: 4000,
+            end: 6001,
+            elves: 2
+        },
+        report.histogram[0]
+    );
+    assert_eq!(
+        vec![2, 0, 1, 1, 0, 0, 0, 0, 0, 1],
+        report
+            .histogram
+            .iter()
+            .map(|bin| bin.elves)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(3, 24000), (2, 11000), (4, 10000)],
+        report
+            .top
+            .iter()
+            .map(|elf| (elf.index, elf.total))
+            .collect::<Vec<_>>()
+    );
+
+    // Ties go to the earlier elf.
+    let report = Report::read("5\n\n2\n3".as_bytes(), 1).unwrap();
+    assert_eq!(0, report.top[0].index);
+    assert_eq!(1, report.histogram.len());
+    assert!(Report::read("".as_bytes(), 3).is_err());
+
+    let mut out = vec![];
+    write_csv("1\n2\n\n3".as_bytes(), &mut out).unwrap();
+    assert_eq!(
+        "elf,items,calories\r\n0,2,3\r\n1,1,3\r\n",
+        String::from_utf8(out).unwrap()
+    );
+}
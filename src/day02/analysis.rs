@@ -0,0 +1,150 @@
+//! How a strategy guide scores under every reading of its second column, since the elf never
+//! quite says what X, Y and Z mean.
+
+use super::{
+    parse_rounds,
+    rules::{Outcome, Rules, Shape},
+    Cipher,
+};
+use anyhow::{anyhow, Result};
+
+/// One way of reading the second column, and what following the guide that way scores.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Reading {
+    /// What each letter stands for, e.g. "X=Rock Y=Paper Z=Scissors".
+    pub(crate) meaning: String,
+    pub(crate) score: usize,
+    /// The expected score if the opponent played at random instead of as the guide says.
+    pub(crate) against_random: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Analysis {
+    /// The letters read as my shapes, under every way of assigning them.
+    pub(crate) mappings: Vec<Reading>,
+    /// The letters read as outcomes.
+    pub(crate) outcomes: Reading,
+    /// What playing the best shape every round would score.
+    pub(crate) best_possible: usize,
+}
+
+/// Every ordering of `0..count`, in lexicographic order.
+pub(crate) fn permutations(count: usize) -> Vec<Vec<usize>> {
+    let mut permutations = vec![vec![]];
+    for _ in 0..count {
+        let mut longer = vec![];
+        for prefix in permutations {
+            for next in (0..count).filter(|next| !prefix.contains(next)) {
+                let mut permutation = prefix.clone();
+                permutation.push(next);
+                longer.push(permutation);
+            }
+        }
+        permutations = longer;
+    }
+    permutations
+}
+
+impl Analysis {
+    pub(crate) fn new(rules: &Rules, cipher: &Cipher, input: &str) -> Result<Self> {
+        let shapes = rules.shapes();
+        let opponents = (0..shapes.len()).map(Shape);
+        let meaning = |letters: &[u8], names: &mut dyn Iterator<Item = String>| {
+            letters
+                .iter()
+                .zip(names)
+                .map(|(&letter, name)| format!("{}={name}", letter as char))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        let rounds = parse_rounds(input, cipher, &cipher.mine, "shape")?;
+        let mappings = permutations(shapes.len())
+            .into_iter()
+            .map(|mapping| {
+                let my_shape = |letter: usize| Shape(mapping[letter]);
+                let score = rounds
+                    .iter()
+                    .map(|&(opponent_shape, letter)| rules.play(opponent_shape, my_shape(letter)))
+                    .sum();
+                let against_random = rounds
+                    .iter()
+                    .map(|&(_, letter)| {
+                        let total = opponents
+                            .clone()
+                            .map(|opponent_shape| rules.play(opponent_shape, my_shape(letter)))
+                            .sum::<usize>();
+                        total as f64 / shapes.len() as f64
+                    })
+                    .sum();
+                let mut names = mapping.iter().map(|&shape| shapes[shape].name.clone());
+                Reading {
+                    meaning: meaning(&cipher.mine, &mut names),
+                    score,
+                    against_random,
+                }
+            })
+            .collect();
+
+        let manifest = |opponent_shape: Shape, outcome: Outcome| {
+            let my_shape = rules.manifest(opponent_shape, outcome).ok_or_else(|| {
+                anyhow!(
+                    "No shape gets a {outcome:?} against {}",
+                    shapes[opponent_shape.0].name
+                )
+            })?;
+            Ok::<_, anyhow::Error>(rules.score(my_shape, outcome))
+        };
+        let mut score = 0;
+        let mut against_random = 0.0;
+        for (opponent_shape, outcome) in parse_rounds(input, cipher, &cipher.outcomes, "outcome")? {
+            let outcome = Outcome::ALL[outcome];
+            score += manifest(opponent_shape, outcome)?;
+            let total = opponents
+                .clone()
+                .map(|opponent_shape| manifest(opponent_shape, outcome))
+                .sum::<Result<usize>>()?;
+            against_random += total as f64 / shapes.len() as f64;
+        }
+        let mut names = Outcome::ALL.iter().map(|outcome| format!("{outcome:?}"));
+        let outcomes = Reading {
+            meaning: meaning(&cipher.outcomes, &mut names),
+            score,
+            against_random,
+        };
+
+        let best_possible = rounds
+            .iter()
+            .map(|&(opponent_shape, _)| {
+                opponents
+                    .clone()
+                    .map(|my_shape| rules.play(opponent_shape, my_shape))
+                    .max()
+                    .unwrap_or_default()
+            })
+            .sum();
+        Ok(Self {
+            mappings,
+            outcomes,
+            best_possible,
+        })
+    }
+
+    /// The mappings that score most.
+    pub(crate) fn highest(&self) -> Vec<&Reading> {
+        let most = self.mappings.iter().map(|reading| reading.score).max();
+        self.mappings
+            .iter()
+            .filter(|reading| Some(reading.score) == most)
+            .collect()
+    }
+
+    /// Every reading, mapping or outcomes, that scores 'target'.
+    pub(crate) fn matching(&self, target: usize) -> Vec<&Reading> {
+        self.mappings
+            .iter()
+            .chain([&self.outcomes])
+            .filter(|reading| reading.score == target)
+            .collect()
+    }
+}
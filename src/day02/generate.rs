@@ -0,0 +1,13 @@
+use crate::generate::Rng;
+
+/// 2500 rounds per unit of scale.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..2500 * scale {
+        input.push(*rng.choose(&['A', 'B', 'C']));
+        input.push(' ');
+        input.push(*rng.choose(&['X', 'Y', 'Z']));
+        input.push('\n');
+    }
+    input
+}
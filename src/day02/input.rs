@@ -1,4 +1,4 @@
-pub(super) const INPUT: &str = "A X
+pub(crate) const INPUT: &str = "A X
 B Z
 C Z
 B Z
@@ -1,163 +1,221 @@
-use self::input::INPUT;
-use std::time::{Duration, Instant};
-
-mod input;
+use self::{
+    analysis::Analysis,
+    input::INPUT,
+    rules::{Outcome, Rules, Shape},
+};
+use crate::{
+    cli::Args,
+    days::Solution,
+    scanner::{ParseError, ScanError, ScanErrorKind, Scanner},
+};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    fs,
+    time::{Duration, Instant},
+};
+
+pub(crate) mod generate;
+pub(crate) mod input;
+
+mod analysis;
+mod rules;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Clone, Copy, Debug)]
-enum Shape {
-    Rock,
-    Paper,
-    Scissors,
-}
-
-impl From<char> for Shape {
-    fn from(c: char) -> Self {
-        match c {
-            'A' | 'X' => Shape::Rock,
-            'B' | 'Y' => Shape::Paper,
-            'C' | 'Z' => Shape::Scissors,
-            _ => panic!(),
-        }
-    }
-}
-
-impl From<Shape> for usize {
-    fn from(shape: Shape) -> Self {
-        match shape {
-            Shape::Rock => 1,
-            Shape::Paper => 2,
-            Shape::Scissors => 3,
-        }
-    }
-}
-
-#[derive(Clone, Copy, Debug)]
-enum Outcome {
-    Win,
-    Loss,
-    Draw,
+pub(crate) const USAGE: &str = "strategy [FILE] [--target SCORE]";
+
+/// What the letters of a strategy guide stand for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Cipher {
+    /// The opponent's letter for each shape, in the order of the rules.
+    opponent: Vec<u8>,
+    /// My letter for each shape, when the second column is a shape.
+    mine: Vec<u8>,
+    /// The letters for a loss, a draw and a win, when the second column is an outcome.
+    outcomes: Vec<u8>,
 }
 
-impl From<char> for Outcome {
-    fn from(c: char) -> Self {
-        match c {
-            'X' => Outcome::Loss,
-            'Y' => Outcome::Draw,
-            'Z' => Outcome::Win,
-            _ => panic!(),
-        }
+impl Cipher {
+    /// A cipher for 'rules', which need a letter for each of their shapes in 'opponent' and
+    /// 'mine'.
+    fn new(rules: &Rules, opponent: &str, mine: &str, outcomes: &str) -> Result<Self> {
+        let letters = |letters: &str, count: usize, what: &str| {
+            let letters = letters.as_bytes().to_vec();
+            let distinct = letters
+                .iter()
+                .enumerate()
+                .all(|(index, letter)| !letters[..index].contains(letter));
+            if letters.len() == count && distinct {
+                Ok(letters)
+            } else {
+                Err(anyhow!("Expected {count} different letters for {what}"))
+            }
+        };
+        let shapes = rules.shapes().len();
+        Ok(Self {
+            opponent: letters(opponent, shapes, "the opponent's shapes")?,
+            mine: letters(mine, shapes, "my shapes")?,
+            outcomes: letters(outcomes, Outcome::ALL.len(), "the outcomes")?,
+        })
     }
-}
 
-impl From<Outcome> for usize {
-    fn from(outcome: Outcome) -> Self {
-        match outcome {
-            Outcome::Win => 6,
-            Outcome::Loss => 0,
-            Outcome::Draw => 3,
-        }
+    /// The puzzle's letters: A, B and C for the opponent's rock, paper and scissors, then either
+    /// X, Y and Z for mine or X, Y and Z for a loss, a draw and a win.
+    fn puzzle() -> Self {
+        Self::new(&Rules::rock_paper_scissors(), "ABC", "XYZ", "XYZ")
+            .expect("the puzzle's letters fit its rules")
     }
 }
 
-impl Outcome {
-    const fn manifest(self, opponent_shape: Shape) -> Shape {
-        match self {
-            Outcome::Win => match opponent_shape {
-                Shape::Rock => Shape::Paper,
-                Shape::Paper => Shape::Scissors,
-                Shape::Scissors => Shape::Rock,
-            },
-            Outcome::Loss => match opponent_shape {
-                Shape::Rock => Shape::Scissors,
-                Shape::Paper => Shape::Rock,
-                Shape::Scissors => Shape::Paper,
-            },
-            Outcome::Draw => opponent_shape,
-        }
-    }
+/// Scan a letter and find it in 'letters', saying it isn't 'what' if it isn't there.
+fn scan_letter(scanner: &mut Scanner, letters: &[u8], what: &str) -> Result<usize, ScanError> {
+    let column = scanner.column();
+    let letter = scanner.char()?;
+    letters
+        .iter()
+        .position(|&known| known as char == letter)
+        .ok_or_else(|| ScanError {
+            line: scanner.line(),
+            column,
+            kind: ScanErrorKind::Unexpected(format!("{what} {letter:?}")),
+        })
 }
 
-const fn outcome(opponent_shape: Shape, my_shape: Shape) -> Outcome {
-    match opponent_shape {
-        Shape::Rock => match my_shape {
-            Shape::Rock => Outcome::Draw,
-            Shape::Paper => Outcome::Win,
-            Shape::Scissors => Outcome::Loss,
-        },
-        Shape::Paper => match my_shape {
-            Shape::Rock => Outcome::Loss,
-            Shape::Paper => Outcome::Draw,
-            Shape::Scissors => Outcome::Win,
-        },
-        Shape::Scissors => match my_shape {
-            Shape::Rock => Outcome::Win,
-            Shape::Paper => Outcome::Loss,
-            Shape::Scissors => Outcome::Draw,
-        },
+/// Each round of a strategy guide as the opponent's shape and the position of the second letter
+/// in 'second'.
+fn parse_rounds(
+    input: &str,
+    cipher: &Cipher,
+    second: &[u8],
+    what: &str,
+) -> Result<Vec<(Shape, usize)>, ParseError> {
+    let mut scanner = Scanner::new(input);
+    let mut rounds = vec![];
+    let mut scan_round = |scanner: &mut Scanner| -> Result<(), ScanError> {
+        let opponent_shape = Shape(scan_letter(scanner, &cipher.opponent, "shape")?);
+        scanner.expect_literal(" ")?;
+        let letter = scan_letter(scanner, second, what)?;
+        scanner.end_of_line()?;
+        rounds.push((opponent_shape, letter));
+        Ok(())
+    };
+    while !scanner.is_at_end() {
+        scan_round(&mut scanner).map_err(|error| ParseError::new(2, input, error))?;
     }
+    Ok(rounds)
 }
 
-fn score(my_shape: Shape, outcome: Outcome) -> usize {
-    usize::from(my_shape) + usize::from(outcome)
-}
-
-fn parse_part1(input: &str) -> Vec<(Shape, Shape)> {
-    input
-        .lines()
-        .map(|line| {
-            (
-                Shape::from(line.chars().next().unwrap()),
-                Shape::from(line.chars().nth(2).unwrap()),
-            )
-        })
-        .collect::<Vec<_>>()
+fn parse_part1(input: &str, cipher: &Cipher) -> Result<Vec<(Shape, Shape)>, ParseError> {
+    let rounds = parse_rounds(input, cipher, &cipher.mine, "shape")?;
+    Ok(rounds
+        .into_iter()
+        .map(|(opponent_shape, my_shape)| (opponent_shape, Shape(my_shape)))
+        .collect())
 }
 
-fn solve_part1(input: &str) -> (usize, Duration) {
+/// Part 1 under any rules and cipher.
+fn play_part1(rules: &Rules, cipher: &Cipher, input: &str) -> Result<(usize, Duration)> {
     let timer = Instant::now();
-    let games = parse_part1(input);
+    let games = parse_part1(input, cipher)?;
     let parse_duration = timer.elapsed();
-    (
+    Ok((
         games
             .iter()
-            .map(|&(opponent_shape, my_shape)| score(my_shape, outcome(opponent_shape, my_shape)))
+            .map(|&(opponent_shape, my_shape)| rules.play(opponent_shape, my_shape))
             .sum::<usize>(),
         parse_duration,
-    )
+    ))
 }
 
-fn parse_part2(input: &str) -> Vec<(Shape, Outcome)> {
-    input
-        .lines()
-        .map(|line| {
-            (
-                Shape::from(line.chars().next().unwrap()),
-                Outcome::from(line.chars().nth(2).unwrap()),
-            )
-        })
-        .collect::<Vec<_>>()
+fn solve_part1(input: &str) -> Result<(usize, Duration)> {
+    play_part1(&Rules::rock_paper_scissors(), &Cipher::puzzle(), input)
 }
 
-fn solve_part2(input: &str) -> (usize, Duration) {
+fn parse_part2(input: &str, cipher: &Cipher) -> Result<Vec<(Shape, Outcome)>, ParseError> {
+    let rounds = parse_rounds(input, cipher, &cipher.outcomes, "outcome")?;
+    Ok(rounds
+        .into_iter()
+        .map(|(opponent_shape, outcome)| (opponent_shape, Outcome::ALL[outcome]))
+        .collect())
+}
+
+/// Part 2 under any rules and cipher.
+fn play_part2(rules: &Rules, cipher: &Cipher, input: &str) -> Result<(usize, Duration)> {
     let timer = Instant::now();
-    let games = parse_part2(input);
+    let games = parse_part2(input, cipher)?;
     let parse_duration = timer.elapsed();
-    (
-        games
-            .iter()
-            .map(|&(opponent_shape, desired_outcome)| {
-                score(desired_outcome.manifest(opponent_shape), desired_outcome)
-            })
-            .sum::<usize>(),
-        parse_duration,
-    )
+    let mut total = 0;
+    for (opponent_shape, desired_outcome) in games {
+        let my_shape = rules
+            .manifest(opponent_shape, desired_outcome)
+            .ok_or_else(|| {
+                anyhow!(
+                    "No shape gets a {desired_outcome:?} against {}",
+                    rules.shapes()[opponent_shape.0].name
+                )
+            })?;
+        total += rules.score(my_shape, desired_outcome);
+    }
+    Ok((total, parse_duration))
+}
+
+fn solve_part2(input: &str) -> Result<(usize, Duration)> {
+    play_part2(&Rules::rock_paper_scissors(), &Cipher::puzzle(), input)
 }
 
 pub(crate) fn solve() -> (usize, usize, Duration) {
-    let part1 = solve_part1(INPUT);
-    let part2 = solve_part2(INPUT);
+    let part1 = solve_part1(INPUT).unwrap();
+    let part2 = solve_part2(INPUT).unwrap();
     (part1.0, part2.0, part1.1 + part2.1)
 }
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let part1 = solve_part1(input)?;
+    let part2 = solve_part2(input)?;
+    Ok(Solution::new(part1.0, part2.0, part1.1 + part2.1))
+}
+
+/// The command line for working out what a strategy guide means: the puzzle's own without a FILE.
+pub(crate) fn run(args: &[String]) -> Result<()> {
+    let args = Args::parse(args, &["target"], &[])?;
+    let input = match args.positional() {
+        [] => INPUT.to_owned(),
+        [file] => fs::read_to_string(file).with_context(|| format!("Reading {file}"))?,
+        files => return Err(anyhow!("Expected one file, not {files:?}")),
+    };
+    let analysis = Analysis::new(&Rules::rock_paper_scissors(), &Cipher::puzzle(), &input)?;
+
+    let width = analysis
+        .mappings
+        .iter()
+        .chain([&analysis.outcomes])
+        .map(|reading| reading.meaning.len())
+        .max()
+        .unwrap_or_default()
+        .max("best possible".len());
+    println!("{:width$}  {:>6}  {:>9}", "reading", "score", "vs random");
+    for reading in analysis.mappings.iter().chain([&analysis.outcomes]) {
+        println!(
+            "{:width$}  {:>6}  {:>9.1}",
+            reading.meaning, reading.score, reading.against_random
+        );
+    }
+    println!("{:width$}  {:>6}", "best possible", analysis.best_possible);
+
+    let meanings = |readings: Vec<&analysis::Reading>| {
+        readings
+            .iter()
+            .map(|reading| reading.meaning.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    println!("\nhighest: {}", meanings(analysis.highest()));
+    if let Some(target) = args.parsed::<usize>("target")? {
+        match analysis.matching(target) {
+            readings if readings.is_empty() => println!("nothing scores {target}"),
+            readings => println!("scoring {target}: {}", meanings(readings)),
+        }
+    }
+    Ok(())
+}
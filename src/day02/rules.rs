@@ -0,0 +1,149 @@
+//! Hand games where every pair of different shapes has a winner, defined as data so that
+//! rock-paper-scissors is one ruleset among many.
+
+use crate::{geometry::Point, grid::Grid};
+use anyhow::{anyhow, Result};
+
+/// A shape, by its position in the [Rules] that define it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Shape(pub(crate) usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    /// In the order their scores are given to [Rules].
+    pub(crate) const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ShapeRule {
+    pub(crate) name: String,
+    /// What playing the shape scores, whatever the outcome.
+    pub(crate) score: usize,
+}
+
+impl ShapeRule {
+    /// Shapes named 'names', scoring 1, 2, 3 and so on in order.
+    pub(crate) fn numbered(names: &[&str]) -> Vec<Self> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| Self {
+                name: (*name).to_owned(),
+                score: index + 1,
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Rules {
+    shapes: Vec<ShapeRule>,
+    /// The outcome of playing the shape at x against the shape at y.
+    outcomes: Grid<Outcome>,
+    /// What a loss, a draw and a win score, in that order.
+    outcome_scores: [usize; 3],
+}
+
+impl Rules {
+    /// A game where each of 'beats' is a winning shape and the shape it beats. Every pair of
+    /// different shapes needs a winner, and only one.
+    pub(crate) fn new(
+        shapes: Vec<ShapeRule>,
+        beats: &[(Shape, Shape)],
+        outcome_scores: [usize; 3],
+    ) -> Result<Self> {
+        let count = shapes.len();
+        if count == 0 {
+            return Err(anyhow!("A game needs at least one shape"));
+        }
+        let mut outcomes = Grid::from_fn(count, count, |point| {
+            (point.x == point.y).then_some(Outcome::Draw)
+        });
+        for &(Shape(winner), Shape(loser)) in beats {
+            if winner.max(loser) >= count {
+                return Err(anyhow!("There are only {count} shapes"));
+            }
+            let name = |shape: usize| &shapes[shape].name;
+            if outcomes[Point::new(winner, loser)].is_some() {
+                return Err(anyhow!(
+                    "{} and {} already have a winner",
+                    name(winner),
+                    name(loser)
+                ));
+            }
+            outcomes[Point::new(winner, loser)] = Some(Outcome::Win);
+            outcomes[Point::new(loser, winner)] = Some(Outcome::Loss);
+        }
+        if let Some(point) = outcomes.position(Option::is_none) {
+            return Err(anyhow!(
+                "Neither of {} and {} beats the other",
+                shapes[point.x].name,
+                shapes[point.y].name
+            ));
+        }
+        Ok(Self {
+            shapes,
+            outcomes: outcomes.map(|outcome| outcome.expect("every pair has an outcome")),
+            outcome_scores,
+        })
+    }
+
+    /// A balanced game of an odd number of shapes in a circle, each beating the half of the
+    /// others just before it.
+    pub(crate) fn cyclic(shapes: Vec<ShapeRule>, outcome_scores: [usize; 3]) -> Result<Self> {
+        let count = shapes.len();
+        if count.is_multiple_of(2) {
+            return Err(anyhow!(
+                "A cyclic game needs an odd number of shapes, not {count}"
+            ));
+        }
+        let beats = (0..count)
+            .flat_map(|winner| {
+                (1..=count / 2)
+                    .map(move |back| (Shape(winner), Shape((winner + count - back) % count)))
+            })
+            .collect::<Vec<_>>();
+        Self::new(shapes, &beats, outcome_scores)
+    }
+
+    /// The puzzle's game.
+    pub(crate) fn rock_paper_scissors() -> Self {
+        Self::cyclic(
+            ShapeRule::numbered(&["Rock", "Paper", "Scissors"]),
+            [0, 3, 6],
+        )
+        .expect("three shapes make a cyclic game")
+    }
+
+    pub(crate) fn shapes(&self) -> &[ShapeRule] {
+        &self.shapes
+    }
+
+    pub(crate) fn outcome(&self, opponent_shape: Shape, my_shape: Shape) -> Outcome {
+        self.outcomes[Point::new(my_shape.0, opponent_shape.0)]
+    }
+
+    pub(crate) fn score(&self, my_shape: Shape, outcome: Outcome) -> usize {
+        self.shapes[my_shape.0].score + self.outcome_scores[outcome as usize]
+    }
+
+    /// What playing 'my_shape' against 'opponent_shape' scores.
+    pub(crate) fn play(&self, opponent_shape: Shape, my_shape: Shape) -> usize {
+        self.score(my_shape, self.outcome(opponent_shape, my_shape))
+    }
+
+    /// The shape to play against 'opponent_shape' to get 'outcome'. Where several would, the one
+    /// scoring most is played. `None` if none would.
+    pub(crate) fn manifest(&self, opponent_shape: Shape, outcome: Outcome) -> Option<Shape> {
+        (0..self.shapes.len())
+            .map(Shape)
+            .filter(|&my_shape| self.outcome(opponent_shape, my_shape) == outcome)
+            .max_by_key(|&my_shape| self.shapes[my_shape.0].score)
+    }
+}
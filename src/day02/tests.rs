@@ -1,4 +1,9 @@
-use super::{input, solve_part1, solve_part2};
+use super::{
+    analysis::{permutations, Analysis},
+    input, play_part1, play_part2,
+    rules::{Outcome, Rules, Shape, ShapeRule},
+    solve_part1, solve_part2, Cipher,
+};
 
 const INPUT: &str= "A Y
 B X
@@ -6,12 +11,139 @@ C Z";
 
 #[test]
 fn part1() {
-    assert_eq!(15, solve_part1(INPUT).0);
-    assert_eq!(17189, solve_part1(input::INPUT).0);
+    assert_eq!(15, solve_part1(INPUT).unwrap().0);
+    assert_eq!(17189, solve_part1(input::INPUT).unwrap().0);
 }
 
 #[test]
 fn part2() {
-    assert_eq!(12, solve_part2(INPUT).0);
-    assert_eq!(13490, solve_part2(input::INPUT).0);
+    assert_eq!(12, solve_part2(INPUT).unwrap().0);
+    assert_eq!(13490, solve_part2(input::INPUT).unwrap().0);
+}
+
+fn rock_paper_scissors_lizard_spock() -> Rules {
+    Rules::cyclic(
+        ShapeRule::numbered(&["Rock", "Spock", "Paper", "Lizard", "Scissors"]),
+        [0, 3, 6],
+    )
+    .unwrap()
+}
+
+#[test]
+fn rules() {
+    let (rock, paper, scissors) = (Shape(0), Shape(1), Shape(2));
+    let rules = Rules::rock_paper_scissors();
+    assert_eq!(Outcome::Win, rules.outcome(rock, paper));
+    assert_eq!(Outcome::Loss, rules.outcome(paper, rock));
+    assert_eq!(Outcome::Win, rules.outcome(paper, scissors));
+    assert_eq!(Outcome::Win, rules.outcome(scissors, rock));
+    assert_eq!(Outcome::Draw, rules.outcome(scissors, scissors));
+    assert_eq!(Some(rock), rules.manifest(paper, Outcome::Loss));
+    assert_eq!(9, rules.play(paper, scissors));
+
+    // Spelled out, the same rules come from data.
+    let spelled_out = Rules::new(
+        ShapeRule::numbered(&["Rock", "Paper", "Scissors"]),
+        &[(paper, rock), (scissors, paper), (rock, scissors)],
+        [0, 3, 6],
+    )
+    .unwrap();
+    assert_eq!(rules, spelled_out);
+
+    let (rock, spock, paper, lizard, scissors) = (Shape(0), Shape(1), Shape(2), Shape(3), Shape(4));
+    let rules = rock_paper_scissors_lizard_spock();
+    for (winner, loser) in [
+        (scissors, paper),
+        (paper, rock),
+        (rock, lizard),
+        (lizard, spock),
+        (spock, scissors),
+        (scissors, lizard),
+        (lizard, paper),
+        (paper, spock),
+        (spock, rock),
+        (rock, scissors),
+    ] {
+        assert_eq!(Outcome::Win, rules.outcome(loser, winner));
+        assert_eq!(Outcome::Loss, rules.outcome(winner, loser));
+    }
+    // Both Spock and paper beat rock, and paper scores more.
+    assert_eq!(Some(paper), rules.manifest(rock, Outcome::Win));
+
+    assert!(Rules::cyclic(ShapeRule::numbered(&["A", "B"]), [0, 3, 6]).is_err());
+    let shapes = || ShapeRule::numbered(&["A", "B", "C"]);
+    let (a, b, c) = (Shape(0), Shape(1), Shape(2));
+    assert!(Rules::new(shapes(), &[(a, b), (b, c)], [0, 3, 6]).is_err());
+    assert!(Rules::new(shapes(), &[(a, b), (b, c), (c, a), (b, a)], [0, 3, 6]).is_err());
+    assert!(Rules::new(shapes(), &[(a, b), (b, c), (c, Shape(3))], [0, 3, 6]).is_err());
+}
+
+#[test]
+fn ciphers() {
+    let rules = rock_paper_scissors_lizard_spock();
+    let cipher = Cipher::new(&rules, "ABCDE", "VWXYZ", "LDW").unwrap();
+    // Spock against rock wins, and lizard against paper.
+    let input = "A W\nC Y\n";
+    assert_eq!(
+        (2 + 6) + (4 + 6),
+        play_part1(&rules, &cipher, input).unwrap().0
+    );
+    // Rock and Spock both lose to paper, and Spock scores more.
+    assert_eq!(2, play_part2(&rules, &cipher, "C L").unwrap().0);
+
+    assert!(Cipher::new(&rules, "ABC", "VWXYZ", "LDW").is_err());
+    assert!(Cipher::new(&rules, "ABCDE", "VWXYY", "LDW").is_err());
+
+    let error = solve_part1("A Y\nB X\nD Z\n").unwrap_err();
+    assert_eq!(
+        "day 2, line 3, column 1: unexpected shape 'D'\n  D Z\n  ^",
+        error.to_string()
+    );
+    let error = solve_part2("A W").unwrap_err();
+    assert_eq!(
+        "day 2, line 1, column 3: unexpected outcome 'W'\n  A W\n    ^",
+        error.to_string()
+    );
+    let error = solve_part1("A é").unwrap_err();
+    assert_eq!(
+        "day 2, line 1, column 3: unexpected shape 'é'\n  A é\n    ^",
+        error.to_string()
+    );
+}
+
+#[test]
+fn analysis() {
+    assert_eq!(vec![Vec::<usize>::new()], permutations(0));
+    let orders = permutations(3);
+    assert_eq!(6, orders.len());
+    assert_eq!(
+        (vec![0, 1, 2], vec![2, 1, 0]),
+        (orders[0].clone(), orders[5].clone())
+    );
+
+    let analysis = Analysis::new(&Rules::rock_paper_scissors(), &Cipher::puzzle(), INPUT).unwrap();
+    assert_eq!(
+        vec![15, 6, 15, 15, 15, 24],
+        analysis
+            .mappings
+            .iter()
+            .map(|reading| reading.score)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!("X=Rock Y=Paper Z=Scissors", analysis.mappings[0].meaning);
+    // Against a random opponent each shape averages its own score plus a draw.
+    assert_eq!(15.0, analysis.mappings[0].against_random);
+    assert_eq!("X=Loss Y=Draw Z=Win", analysis.outcomes.meaning);
+    assert_eq!(
+        (12, 15.0),
+        (analysis.outcomes.score, analysis.outcomes.against_random)
+    );
+    assert_eq!(24, analysis.best_possible);
+
+    let highest = analysis.highest();
+    assert_eq!(1, highest.len());
+    assert_eq!("X=Scissors Y=Paper Z=Rock", highest[0].meaning);
+    assert_eq!(4, analysis.matching(15).len());
+    assert_eq!(vec![&analysis.outcomes], analysis.matching(12));
+    assert!(analysis.matching(13).is_empty());
 }
@@ -0,0 +1,36 @@
+use crate::generate::Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const COMPARTMENT_SIZE: usize = 12;
+
+/// 100 groups of three rucksacks per unit of scale.
+///
+/// Each group shares exactly one badge, and each rucksack has exactly one item type in both
+/// compartments. Everything else is drawn from item types private to one compartment of one rucksack.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..100 * scale {
+        let mut items = ITEMS.to_vec();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        let duplicates = &items[1..4];
+        let private = &items[4..];
+        for (rucksack, &duplicate) in duplicates.iter().enumerate() {
+            let left_only = &private[rucksack * 16..rucksack * 16 + 8];
+            let right_only = &private[rucksack * 16 + 8..rucksack * 16 + 16];
+            let mut left = vec![duplicate, badge];
+            let mut right = vec![duplicate];
+            while left.len() != COMPARTMENT_SIZE {
+                left.push(*rng.choose(left_only));
+            }
+            while right.len() != COMPARTMENT_SIZE {
+                right.push(*rng.choose(right_only));
+            }
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            input.extend(left.iter().chain(right.iter()).map(|&item| item as char));
+            input.push('\n');
+        }
+    }
+    input
+}
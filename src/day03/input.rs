@@ -1,4 +1,4 @@
-pub(super) const INPUT: &str = "shzsFcPssFhjFssBzdpRcNHNZrpdJdJVJZ
+pub(crate) const INPUT: &str = "shzsFcPssFhjFssBzdpRcNHNZrpdJdJVJZ
 fwvMCntfCCbSbSbtDgDNrDtDtJHZVH
 GbCwwbwwnGrLhBzjFFFsWPhL
 PpCqRsqqmmtCwMJC
@@ -1,70 +1,186 @@
 use self::input::INPUT;
-use std::time::Duration;
+use crate::{
+    days::Solution,
+    scanner::{ParseError, ScanError, ScanErrorKind},
+};
+use anyhow::{anyhow, Result};
+use std::time::{Duration, Instant};
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
 
-fn priority(c: char) -> u8 {
-    match c {
-        'a'..='z' => c as u8 - b'a' + 1,
-        'A'..='Z' => c as u8 - b'A' + 27,
-        _ => panic!(),
+/// How many compartments the puzzle's rucksacks have.
+const COMPARTMENTS: usize = 2;
+
+/// How many elves share a badge in the puzzle.
+const GROUP_SIZE: usize = 3;
+
+fn priority(item: u8) -> Option<u8> {
+    match item {
+        b'a'..=b'z' => Some(item - b'a' + 1),
+        b'A'..=b'Z' => Some(item - b'A' + 27),
+        _ => None,
+    }
+}
+
+/// A set of item types, with a bit for each priority from 1 to 52.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Items(u64);
+
+impl Items {
+    /// Every item type there is.
+    const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    fn insert(&mut self, priority: u8) {
+        self.0 |= 1 << priority;
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// The priority of the only item type in the set, if it has exactly one.
+    fn only(self) -> Option<usize> {
+        (self.len() == 1).then(|| self.0.trailing_zeros() as usize)
     }
 }
 
-fn solve_part1(input: &str) -> usize {
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Rucksack {
+    /// Where the rucksack is in the input, counting from 1.
+    line: usize,
+    compartments: Vec<Items>,
+}
+
+impl Rucksack {
+    /// Everything in the rucksack, whichever compartment it's in.
+    fn items(&self) -> Items {
+        self.compartments
+            .iter()
+            .fold(Items::default(), |items, &compartment| {
+                items.union(compartment)
+            })
+    }
+}
+
+/// Every rucksack, each split into 'compartments' of equal size.
+fn parse(input: &str, compartments: usize) -> Result<Vec<Rucksack>, ParseError> {
     input
         .lines()
-        .map(|line| {
-            let (a, b) = line.split_at(line.len() / 2);
-            let duplicate = a
-                .chars()
-                .find_map(|c| b.chars().find(|&b_c| c == b_c))
-                .unwrap();
-            let priority_of_duplicate = priority(duplicate);
-            priority_of_duplicate as usize
+        .enumerate()
+        .map(|(index, line)| {
+            let error = |column, kind| {
+                let error = ScanError {
+                    line: index + 1,
+                    column,
+                    kind,
+                };
+                ParseError::at_line(3, line, error)
+            };
+            if compartments == 0 || !line.len().is_multiple_of(compartments) {
+                return Err(error(
+                    1,
+                    ScanErrorKind::Unexpected(format!(
+                        "{} items, which don't split into {compartments} compartments",
+                        line.len()
+                    )),
+                ));
+            }
+            let mut items = vec![Items::default(); compartments];
+            let size = line.len() / compartments;
+            for (column, &item) in line.as_bytes().iter().enumerate() {
+                let priority = priority(item).ok_or_else(|| {
+                    // The first byte that isn't a letter starts a character, maybe a longer one.
+                    let c = line[column..].chars().next().unwrap_or(item as char);
+                    let what = format!("item {c:?}");
+                    error(column + 1, ScanErrorKind::Unexpected(what))
+                })?;
+                items[column / size].insert(priority);
+            }
+            Ok(Rucksack {
+                line: index + 1,
+                compartments: items,
+            })
         })
-        .sum::<usize>()
+        .collect()
 }
 
-fn solve_part2(input: &str) -> usize {
-    let line_count = input.lines().count();
-    let mut lines = input.lines();
-    let mut i = 0;
-    let mut sum = 0;
-
-    while i != line_count {
-        let a = lines.next().unwrap();
-        let b = lines.next().unwrap();
-        let c = lines.next().unwrap();
-
-        let mut found = false;
-        for x in a.chars() {
-            for y in b.chars() {
-                if x != y {
-                    continue;
-                }
-                if c.chars().any(|z| z == y) {
-                    sum += priority(x) as usize;
-                    found = true;
-                    break;
-                }
-                if found {
-                    break;
-                }
-            }
-            if found {
-                break;
-            }
-        }
+/// The sum of the priorities of the item types in every compartment of a rucksack, of which each
+/// rucksack must have exactly one.
+fn misplaced_priorities(rucksacks: &[Rucksack]) -> Result<usize> {
+    rucksacks
+        .iter()
+        .map(|rucksack| {
+            let shared = rucksack
+                .compartments
+                .iter()
+                .fold(Items::ALL, |shared, &compartment| {
+                    shared.intersection(compartment)
+                });
+            shared.only().ok_or_else(|| {
+                anyhow!(
+                    "The compartments of the rucksack on line {} share {} item types, not one",
+                    rucksack.line,
+                    shared.len()
+                )
+            })
+        })
+        .sum()
+}
 
-        i += 3;
+/// The sum of the priorities of each group's badge: the one item type all the group's rucksacks
+/// have. Every rucksack must be in a full group.
+fn badge_priorities(rucksacks: &[Rucksack], group_size: usize) -> Result<usize> {
+    if group_size == 0 || !rucksacks.len().is_multiple_of(group_size) {
+        return Err(anyhow!(
+            "{} rucksacks don't make groups of {group_size}",
+            rucksacks.len()
+        ));
     }
-    sum
+    rucksacks
+        .chunks(group_size)
+        .map(|group| {
+            let shared = group.iter().fold(Items::ALL, |shared, rucksack| {
+                shared.intersection(rucksack.items())
+            });
+            shared.only().ok_or_else(|| {
+                anyhow!(
+                    "The group from line {} shares {} item types, not one badge",
+                    group[0].line,
+                    shared.len()
+                )
+            })
+        })
+        .sum()
+}
+
+fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
+    let timer = Instant::now();
+    let rucksacks = parse(input, COMPARTMENTS)?;
+    let parse_duration = timer.elapsed();
+    Ok((
+        misplaced_priorities(&rucksacks)?,
+        badge_priorities(&rucksacks, GROUP_SIZE)?,
+        parse_duration,
+    ))
 }
 
 pub(crate) fn solve() -> (usize, usize, Duration) {
-    (solve_part1(INPUT), solve_part2(INPUT), Duration::new(0, 0))
+    solve_for(INPUT).unwrap()
+}
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
 }
@@ -1,4 +1,4 @@
-use super::{input, solve_part1, solve_part2};
+use super::{badge_priorities, input, misplaced_priorities, parse, solve_for, Items};
 
 const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
 jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
@@ -9,12 +9,58 @@ CrZsJsPPZsGzwwsLwLmpwMDw";
 
 #[test]
 fn part1() {
-    assert_eq!(157, solve_part1(INPUT));
-    assert_eq!(8185, solve_part1(input::INPUT));
+    assert_eq!(157, solve_for(INPUT).unwrap().0);
+    assert_eq!(8185, solve_for(input::INPUT).unwrap().0);
 }
 
 #[test]
 fn part2() {
-    assert_eq!(70, solve_part2(INPUT));
-    assert_eq!(2817, solve_part2(input::INPUT));
+    assert_eq!(70, solve_for(INPUT).unwrap().1);
+    assert_eq!(2817, solve_for(input::INPUT).unwrap().1);
+}
+
+#[test]
+fn items() {
+    let mut items = Items::default();
+    items.insert(1);
+    assert_eq!(Some(1), items.only());
+    items.insert(52);
+    assert_eq!((2, None), (items.len(), items.only()));
+    assert_eq!(52, Items::ALL.len());
+    assert_eq!(items, Items::ALL.intersection(items));
+}
+
+#[test]
+fn groups_and_compartments() {
+    // The compartments share 'a', 'a', 'b' and 'a', and the pairs of rucksacks 'a' and 'b'.
+    let rucksacks = parse("axayaz\nabacad\nbebfbg\nabaiaj", 3).unwrap();
+    assert_eq!(3, rucksacks[0].compartments.len());
+    assert_eq!(1 + 1 + 2 + 1, misplaced_priorities(&rucksacks).unwrap());
+    assert_eq!(2 + 1, badge_priorities(&rucksacks, 2).unwrap());
+    assert_eq!(
+        "The group from line 1 shares 0 item types, not one badge",
+        badge_priorities(&rucksacks, 4).unwrap_err().to_string()
+    );
+    assert_eq!(
+        "4 rucksacks don't make groups of 3",
+        badge_priorities(&rucksacks, 3).unwrap_err().to_string()
+    );
+    assert!(badge_priorities(&rucksacks, 0).is_err());
+
+    assert_eq!(
+        "The compartments of the rucksack on line 2 share 0 item types, not one",
+        solve_for("aa\nab").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "day 3, line 2, column 1: unexpected 3 items, which don't split into 2 compartments\n  abc\n  ^",
+        solve_for("aa\nabc").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "day 3, line 1, column 2: unexpected item '1'\n  a1\n   ^",
+        solve_for("a1").unwrap_err().to_string()
+    );
+    assert_eq!(
+        "day 3, line 1, column 3: unexpected item 'é'\n  abé\n    ^",
+        solve_for("abé").unwrap_err().to_string()
+    );
 }
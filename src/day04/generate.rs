@@ -0,0 +1,17 @@
+use crate::generate::Rng;
+
+/// 1000 pairs per unit of scale.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    let range = |rng: &mut Rng| {
+        let from = rng.range(1, 100);
+        let to = rng.range(from, 100);
+        format!("{from}-{to}")
+    };
+    for _ in 0..1000 * scale {
+        let a = range(rng);
+        let b = range(rng);
+        input.push_str(&format!("{a},{b}\n"));
+    }
+    input
+}
@@ -1,4 +1,4 @@
-pub(super) const INPUT: &str = "8-17,16-49
+pub(crate) const INPUT: &str = "8-17,16-49
 17-38,18-36
 17-43,43-43
 86-94,7-87
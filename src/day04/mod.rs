@@ -1,68 +1,50 @@
 use self::input::INPUT;
-use anyhow::Result;
-use std::{
-    num::ParseIntError,
-    str::FromStr,
-    time::{Duration, Instant},
+use crate::{
+    days::Solution,
+    interval::Interval,
+    scanner::{ParseError, ScanError, Scanner},
 };
+use anyhow::Result;
+use std::time::{Duration, Instant};
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
 
-/// Inclusive range.
-struct Range {
-    from: u8,
-    to: u8,
-}
-
-impl FromStr for Range {
-    type Err = ParseIntError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut section_range = s.split('-').map(|s| s.parse::<u8>());
-        Ok(Self {
-            from: section_range.next().unwrap()?,
-            to: section_range.next().unwrap()?,
-        })
-    }
+/// A range of sections, given by its first and last. Sections fit in a byte, but the interval's
+/// end is one past the last.
+fn scan_range(scanner: &mut Scanner) -> Result<Interval<u16>, ScanError> {
+    let first = scanner.unsigned::<u8>()?;
+    scanner.expect_literal("-")?;
+    let last = scanner.unsigned::<u8>()?;
+    Ok(Interval::inclusive(first.into(), last.into()))
 }
 
-impl Range {
-    fn overlaps(&self, other: &Range) -> bool {
-        !(self.from > other.to || self.to < other.from)
-    }
-}
+/// The sections each of a pair of elves is assigned.
+type Pair = (Interval<u16>, Interval<u16>);
 
-fn symmetric_contains(a: &Range, b: &Range) -> bool {
-    if a.from <= b.from {
-        if a.to >= b.to {
-            true // a contains b
-        } else {
-            a.from == b.from // b contains a
-        }
-    } else {
-        a.to <= b.to
+fn scan_pairs(input: &str) -> Result<Vec<Pair>, ScanError> {
+    let mut elves = vec![];
+    let mut scanner = Scanner::new(input);
+    while !scanner.is_at_end() {
+        let a = scan_range(&mut scanner)?;
+        scanner.expect_literal(",")?;
+        let b = scan_range(&mut scanner)?;
+        scanner.end_of_line()?;
+        elves.push((a, b));
     }
+    Ok(elves)
 }
 
 fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
     let timer = Instant::now();
-    let elves = input
-        .lines()
-        .map(|line| {
-            let mut elves = line.split(',').map(|s| s.parse::<Range>());
-            (
-                elves.next().unwrap().unwrap(),
-                elves.next().unwrap().unwrap(),
-            )
-        })
-        .collect::<Vec<_>>();
+    let elves = scan_pairs(input).map_err(|error| ParseError::new(4, input, error))?;
     let parse_duration = timer.elapsed();
     let part1 = elves
         .iter()
-        .filter(|(a, b)| symmetric_contains(a, b))
+        .filter(|(a, b)| a.encloses(b) || b.encloses(a))
         .count();
     let part2 = elves.iter().filter(|(a, b)| a.overlaps(b)).count();
     Ok((part1, part2, parse_duration))
@@ -76,4 +58,9 @@ pub(crate) fn solve() -> (usize, usize, Duration) {
             (0, 0, Duration::new(0, 0))
         }
     }
-}
\ No newline at end of file
+}
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}
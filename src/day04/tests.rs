@@ -18,3 +18,12 @@ fn part2() {
     assert_eq!(4, solve_for(INPUT).unwrap().1);
     assert_eq!(806, solve_for(input::INPUT).unwrap().1);
 }
+
+#[test]
+fn parse_error() {
+    let error = solve_for("2-4,6-8\n2-3,4\n").unwrap_err().to_string();
+    assert_eq!(
+        "day 4, line 2, column 6: expected \"-\"\n  2-3,4\n       ^",
+        error
+    );
+}
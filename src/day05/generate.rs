@@ -0,0 +1,49 @@
+use crate::generate::Rng;
+
+const STACK_COUNT: usize = 9;
+
+/// Nine stacks and 500 steps per unit of scale.
+///
+/// Steps never empty a stack, so every stack still has a top crate at the end.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut stacks = (0..STACK_COUNT)
+        .map(|_| {
+            (0..rng.range(2, 9))
+                .map(|_| (b'A' + rng.below(26) as u8) as char)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut input = String::new();
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_owned(),
+            })
+            .collect::<Vec<_>>();
+        input.push_str(&row.join(" "));
+        input.push('\n');
+    }
+    let labels = (1..=STACK_COUNT)
+        .map(|label| format!(" {label} "))
+        .collect::<Vec<_>>();
+    input.push_str(&labels.join(" "));
+    input.push_str("\n\n");
+
+    for _ in 0..500 * scale {
+        let sources = (0..STACK_COUNT)
+            .filter(|&stack| stacks[stack].len() > 1)
+            .collect::<Vec<_>>();
+        let from = *rng.choose(&sources);
+        let to = (from + 1 + rng.below(STACK_COUNT - 1)) % STACK_COUNT;
+        let count = 1 + rng.below(stacks[from].len() - 1);
+        let remaining = stacks[from].len() - count;
+        let moved = stacks[from].split_off(remaining);
+        stacks[to].extend(moved);
+        input.push_str(&format!("move {count} from {} to {}\n", from + 1, to + 1));
+    }
+    input
+}
@@ -1,4 +1,4 @@
-pub(super) const INPUT: &str = "[N]     [Q]         [N]            
+pub(crate) const INPUT: &str = "[N]     [Q]         [N]            
 [R]     [F] [Q]     [G] [M]        
 [J]     [Z] [T]     [R] [H] [J]    
 [T] [H] [G] [R]     [B] [N] [T]    
@@ -1,12 +1,13 @@
 use self::input::INPUT;
-use anyhow::Result;
-use std::{
-    num::ParseIntError,
-    str::FromStr,
-    time::{Duration, Instant},
+use crate::{
+    days::Solution,
+    scanner::{ParseError, ScanError, ScanErrorKind, Scanner},
 };
+use anyhow::{anyhow, Result};
+use std::time::{Duration, Instant};
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
@@ -15,20 +16,44 @@ type Stack = Vec<char>;
 
 #[derive(Debug)]
 pub(crate) struct Step {
+    /// Where the step is in the input, for errors.
+    line: usize,
     count: usize,
     from: usize,
     to: usize,
 }
 
-impl FromStr for Step {
-    type Err = ParseIntError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split(' ').skip(1).step_by(2);
+impl Step {
+    /// Stacks are numbered from 1 to 'stack_count' in the input, and indexed from 0 here.
+    fn scan(scanner: &mut Scanner, stack_count: usize) -> Result<Self, ScanError> {
+        let stack = |scanner: &mut Scanner| {
+            let column = scanner.column();
+            let stack = scanner.unsigned::<usize>()?;
+            if (1..=stack_count).contains(&stack) {
+                Ok(stack - 1)
+            } else {
+                Err(ScanError {
+                    line: scanner.line(),
+                    column,
+                    kind: ScanErrorKind::Unexpected(format!(
+                        "stack {stack}, expected 1 to {stack_count}"
+                    )),
+                })
+            }
+        };
+        let line = scanner.line();
+        scanner.expect_literal("move ")?;
+        let count = scanner.unsigned::<usize>()?;
+        scanner.expect_literal(" from ")?;
+        let from = stack(scanner)?;
+        scanner.expect_literal(" to ")?;
+        let to = stack(scanner)?;
+        scanner.end_of_line()?;
         Ok(Self {
-            count: tokens.next().unwrap().parse::<usize>()?,
-            from: tokens.next().unwrap().parse::<usize>()? - 1,
-            to: tokens.next().unwrap().parse::<usize>()? - 1,
+            line,
+            count,
+            from,
+            to,
         })
     }
 }
@@ -39,7 +64,7 @@ pub(crate) fn parse(input: &str) -> Result<(Vec<Stack>, Vec<Step>)> {
     let stack_count = initial_stack_state
         .lines()
         .last()
-        .unwrap()
+        .ok_or_else(|| anyhow!("Missing the stacks"))?
         .split_whitespace()
         .count();
     let mut stacks = vec![Vec::new(); stack_count];
@@ -56,20 +81,40 @@ pub(crate) fn parse(input: &str) -> Result<(Vec<Stack>, Vec<Step>)> {
         stack.reverse();
         stack.retain(|&c| c != ' ');
     });
-    let steps = sections.next().unwrap();
-    let steps = steps
-        .lines()
-        .map(|line| -> Result<Step, ParseIntError> { line.parse::<Step>() })
-        .collect::<Result<Vec<_>, ParseIntError>>()?;
+    let steps_section = sections
+        .next()
+        .ok_or_else(|| anyhow!("Missing the rearrangement procedure"))?;
+    // The drawing and the blank line separating it from the procedure come first.
+    let first_step_line = initial_stack_state.lines().count() + 2;
+    let mut scanner = Scanner::new(steps_section).starting_at_line(first_step_line);
+    let mut steps = vec![];
+    while !scanner.is_at_end() {
+        steps.push(
+            Step::scan(&mut scanner, stack_count)
+                .map_err(|error| ParseError::new(5, input, error))?,
+        );
+    }
     Ok((stacks, steps))
 }
 
-pub(crate) fn solve_for(stacks: &mut [Stack], steps: &[Step]) -> (String, String) {
+pub(crate) fn solve_for(stacks: &mut [Stack], steps: &[Step]) -> Result<(String, String)> {
     #[allow(clippy::useless_asref)]
     let mut part1_stacks = Vec::from(stacks.as_mut());
     let part2_stacks = stacks;
 
-    steps.iter().for_each(|step| {
+    for step in steps {
+        // Both parts move the same number of crates between the same stacks, so their stacks are
+        // always the same heights and checking one checks both.
+        let height = part1_stacks[step.from].len();
+        if height < step.count {
+            return Err(anyhow!(
+                "Line {} moves {} crates from stack {}, which only has {height}",
+                step.line,
+                step.count,
+                step.from + 1
+            ));
+        }
+
         // part 1
         (0..step.count).for_each(|_| {
             let c = part1_stacks[step.from].pop().unwrap();
@@ -88,22 +133,29 @@ pub(crate) fn solve_for(stacks: &mut [Stack], steps: &[Step]) -> (String, String
 
         let from = &mut part2_stacks[step.from];
         from.resize(from.len() - step.count, ' ');
-    });
+    }
 
-    let stack_tops = |stacks:&[Vec<char>]| {stacks
-        .iter()
-        .map(|stack| stack.last().unwrap())
-        .collect::<String>()
+    let stack_tops = |stacks: &[Vec<char>]| {
+        stacks
+            .iter()
+            .enumerate()
+            .map(|(index, stack)| {
+                stack
+                    .last()
+                    .ok_or_else(|| anyhow!("Stack {} ends up empty", index + 1))
+            })
+            .collect::<Result<String>>()
     };
-    (stack_tops(&part1_stacks), stack_tops(part2_stacks))
+    Ok((stack_tops(&part1_stacks)?, stack_tops(part2_stacks)?))
 }
 
 pub(crate) fn solve() -> (String, String, Duration) {
     let timer = Instant::now();
     let parsed = parse(INPUT);
     let parse_duration = Instant::now() - timer;
-    let (part1, part2) = match parsed {
-        Ok((mut stacks, steps)) => solve_for(&mut stacks, &steps),
+    let solved = parsed.and_then(|(mut stacks, steps)| solve_for(&mut stacks, &steps));
+    let (part1, part2) = match solved {
+        Ok(answers) => answers,
         Err(error) => {
             println!("day 5 error: {}", error);
             ("".to_owned(), "".to_owned())
@@ -111,3 +163,11 @@ pub(crate) fn solve() -> (String, String, Duration) {
     };
     (part1, part2, parse_duration)
 }
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let timer = Instant::now();
+    let (mut stacks, steps) = parse(input)?;
+    let parse_duration = timer.elapsed();
+    let (part1, part2) = solve_for(&mut stacks, &steps)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}
@@ -15,17 +15,60 @@ move 1 from 1 to 2";
 #[test]
 fn part1() {
     let (mut example_stacks, example_steps) = parse(INPUT).unwrap();
-    assert_eq!("CMZ", solve_for(&mut example_stacks, &example_steps).0);
+    assert_eq!(
+        "CMZ",
+        solve_for(&mut example_stacks, &example_steps).unwrap().0
+    );
 
     let (mut stacks, steps) = parse(input::INPUT).unwrap();
-    assert_eq!("QNNTGTPFN", solve_for(&mut stacks, &steps).0);
+    assert_eq!("QNNTGTPFN", solve_for(&mut stacks, &steps).unwrap().0);
 }
 
 #[test]
 fn part2() {
     let (mut example_stacks, example_steps) = parse(INPUT).unwrap();
-    assert_eq!("MCD", solve_for(&mut example_stacks, &example_steps).1);
+    assert_eq!(
+        "MCD",
+        solve_for(&mut example_stacks, &example_steps).unwrap().1
+    );
 
     let (mut stacks, steps) = parse(input::INPUT).unwrap();
-    assert_eq!("GGNPJBTTR", solve_for(&mut stacks, &steps).1);
+    assert_eq!("GGNPJBTTR", solve_for(&mut stacks, &steps).unwrap().1);
+}
+
+#[test]
+fn parse_error() {
+    let input = INPUT.replacen("move 3", "move three", 1);
+    let error = parse(&input).unwrap_err().to_string();
+    assert!(
+        error.starts_with("day 5, line 7, column 6: expected a digit"),
+        "{error}"
+    );
+}
+
+#[test]
+fn bad_steps() {
+    let error = |input: &str| {
+        match parse(input) {
+            Ok((mut stacks, steps)) => solve_for(&mut stacks, &steps).unwrap_err(),
+            Err(error) => error,
+        }
+        .to_string()
+    };
+    let step = |step| INPUT.replacen("move 3 from 1 to 3", step, 1);
+    assert_eq!(
+        "day 5, line 7, column 13: unexpected stack 0, expected 1 to 3\n  move 3 from 0 to 3\n              ^",
+        error(&step("move 3 from 0 to 3"))
+    );
+    assert!(error(&step("move 3 from 1 to 4"))
+        .starts_with("day 5, line 7, column 18: unexpected stack 4"));
+    assert_eq!(
+        "Line 7 moves 4 crates from stack 1, which only has 3",
+        error(&step("move 4 from 1 to 3"))
+    );
+    // The last step takes the only crate left on stack 1.
+    assert_eq!(
+        "Stack 1 ends up empty",
+        error(&format!("{INPUT}\nmove 1 from 1 to 3"))
+    );
 }
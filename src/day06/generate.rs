@@ -0,0 +1,11 @@
+use crate::generate::Rng;
+
+/// 4096 characters per unit of scale, with both markers at the very end.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    // Three letters can never form a start-of-packet marker, so the whole stream has to be searched.
+    let mut input = (0..4096 * scale)
+        .map(|_| *rng.choose(&['a', 'b', 'c']))
+        .collect::<String>();
+    input.push_str("defghijklmnopqrstu");
+    input
+}
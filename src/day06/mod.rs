@@ -1,7 +1,13 @@
 use self::input::INPUT;
-use std::time::Duration;
+use crate::{
+    days::Solution,
+    scanner::{ParseError, ScanError, ScanErrorKind, Scanner},
+};
+use anyhow::{anyhow, Result};
+use std::time::{Duration, Instant};
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
@@ -14,27 +20,52 @@ fn is_marker(m: &str, count: usize) -> bool {
     })
 }
 
-fn solve_for_marker_length(input: &str, input_length: usize, marker_length: usize) -> usize {
-    (0..input_length - marker_length)
-        .find_map(|i| {
-            let end = i + marker_length;
-            let marker_candidate = &input[i..end];
-            if is_marker(marker_candidate, marker_length) {
-                Some(end)
-            } else {
-                None
-            }
-        })
-        .unwrap()
+/// How many characters have been read when the first marker ends, if there is one.
+fn solve_for_marker_length(signal: &str, marker_length: usize) -> Result<usize> {
+    (marker_length..=signal.len())
+        .find(|&end| is_marker(&signal[end - marker_length..end], marker_length))
+        .ok_or_else(|| anyhow!("No {marker_length} characters in a row are all different"))
 }
 
-fn solve_for(input: &str) -> (usize, usize, Duration) {
-    let count = input.len();
-    let part1 = solve_for_marker_length(input, count, 4);
-    let part2 = solve_for_marker_length(input, count, 14);
-    (part1, part2, Duration::new(0, 0))
+/// The signal is one line of lowercase letters.
+fn scan_signal(input: &str) -> Result<&str, ScanError> {
+    let mut scanner = Scanner::new(input);
+    let signal = scanner.rest_of_line();
+    if let Some(column) = signal.find(|c: char| !c.is_ascii_lowercase()) {
+        let c = signal[column..].chars().next().unwrap_or_default();
+        return Err(ScanError {
+            line: 1,
+            column: column + 1,
+            kind: ScanErrorKind::Unexpected(format!("{c:?}, expected a letter")),
+        });
+    }
+    scanner.end_of_line()?;
+    if !scanner.is_at_end() {
+        return Err(scanner.error(ScanErrorKind::Unexpected("second line".to_owned())));
+    }
+    Ok(signal)
+}
+
+fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
+    let timer = Instant::now();
+    let signal = scan_signal(input).map_err(|error| ParseError::new(6, input, error))?;
+    let parse_duration = timer.elapsed();
+    let part1 = solve_for_marker_length(signal, 4)?;
+    let part2 = solve_for_marker_length(signal, 14)?;
+    Ok((part1, part2, parse_duration))
 }
 
 pub(crate) fn solve() -> (usize, usize, Duration) {
-    solve_for(INPUT)
+    match solve_for(INPUT) {
+        Ok(solution) => solution,
+        Err(error) => {
+            println!("day 6 error: {error}");
+            (0, 0, Duration::new(0, 0))
+        }
+    }
+}
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
 }
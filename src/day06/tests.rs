@@ -2,20 +2,44 @@ use super::{input, solve_for};
 
 #[test]
 fn part1() {
-    assert_eq!(7, solve_for("mjqjpqmgbljsphdztnvjfqwrcgsmlb").0);
-    assert_eq!(5, solve_for("bvwbjplbgvbhsrlpgdmjqwftvncz").0);
-    assert_eq!(6, solve_for("nppdvjthqldpwncqszvftbrmjlhg").0);
-    assert_eq!(10, solve_for("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").0);
-    assert_eq!(11, solve_for("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").0);
-    assert_eq!(1300, solve_for(input::INPUT).0);
+    assert_eq!(7, solve_for("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap().0);
+    assert_eq!(5, solve_for("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap().0);
+    assert_eq!(6, solve_for("nppdvjthqldpwncqszvftbrmjlhg").unwrap().0);
+    assert_eq!(
+        10,
+        solve_for("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap().0
+    );
+    assert_eq!(11, solve_for("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap().0);
+    assert_eq!(1300, solve_for(input::INPUT).unwrap().0);
 }
 
 #[test]
 fn part2() {
-    assert_eq!(19, solve_for("mjqjpqmgbljsphdztnvjfqwrcgsmlb").1);
-    assert_eq!(23, solve_for("bvwbjplbgvbhsrlpgdmjqwftvncz").1);
-    assert_eq!(23, solve_for("nppdvjthqldpwncqszvftbrmjlhg").1);
-    assert_eq!(29, solve_for("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").1);
-    assert_eq!(26, solve_for("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").1);
-    assert_eq!(3986, solve_for(input::INPUT).1);
+    assert_eq!(19, solve_for("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap().1);
+    assert_eq!(23, solve_for("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap().1);
+    assert_eq!(23, solve_for("nppdvjthqldpwncqszvftbrmjlhg").unwrap().1);
+    assert_eq!(
+        29,
+        solve_for("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap().1
+    );
+    assert_eq!(26, solve_for("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap().1);
+    assert_eq!(3986, solve_for(input::INPUT).unwrap().1);
+}
+
+#[test]
+fn bad_signals() {
+    let error = solve_for("abcdeFgh").unwrap_err().to_string();
+    assert!(
+        error.starts_with("day 6, line 1, column 6: unexpected 'F', expected a letter"),
+        "{error}"
+    );
+    let error = solve_for("abcd\nefgh").unwrap_err().to_string();
+    assert!(
+        error.starts_with("day 6, line 2, column 1: unexpected second line"),
+        "{error}"
+    );
+    // Too short for a start-of-message marker, and then for any marker at all.
+    assert!(solve_for("abcd").is_err());
+    assert!(solve_for("ab").is_err());
+    assert!(solve_for("").is_err());
 }
@@ -0,0 +1,58 @@
+use crate::generate::Rng;
+
+/// The mean file size that puts about 55M on a disk of 'directory_count' directories. Files are
+/// at least 1 byte though, so past about 14M directories the disk fills up whatever the sizes.
+pub(crate) fn mean_file_size(directory_count: usize) -> usize {
+    // Each directory holds 1 to 7 files, so about 4 on average.
+    (55_000_000 / (directory_count.max(1) * 4)).max(1)
+}
+
+/// About 200 directories per unit of scale, and at least one.
+///
+/// File sizes shrink as the tree grows so the disk stays between 40M and 70M used, which is what
+/// both parts of the puzzle assume.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let directory_count = (200 * scale).max(1);
+    let mean_file_size = mean_file_size(directory_count);
+    let mut input = String::from("$ cd /\n");
+    let mut next_directory = 1;
+    let mut next_file = 1;
+    let mut remaining = directory_count - 1;
+    // Directories waiting to be listed, as (depth, name). The root is listed first.
+    let mut stack = vec![(0, String::new())];
+    let mut depth = 0;
+    while let Some((target_depth, name)) = stack.pop() {
+        while target_depth != 0 && depth >= target_depth {
+            input.push_str("$ cd ..\n");
+            depth -= 1;
+        }
+        if target_depth != 0 {
+            input.push_str(&format!("$ cd {name}\n"));
+            depth += 1;
+        }
+        input.push_str("$ ls\n");
+        let sub_directory_count = if remaining == 0 {
+            0
+        } else {
+            rng.below(4.min(remaining) + 1)
+        };
+        let sub_directory_count = if stack.is_empty() && remaining != 0 {
+            sub_directory_count.max(1)
+        } else {
+            sub_directory_count
+        };
+        remaining -= sub_directory_count;
+        for _ in 0..sub_directory_count {
+            let name = format!("d{next_directory}");
+            next_directory += 1;
+            input.push_str(&format!("dir {name}\n"));
+            stack.push((depth + 1, name));
+        }
+        for _ in 0..rng.range(1, 8) {
+            let size = rng.range(1, 2 * mean_file_size as i64);
+            input.push_str(&format!("{size} f{next_file}.txt\n"));
+            next_file += 1;
+        }
+    }
+    input
+}
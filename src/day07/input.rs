@@ -1,4 +1,4 @@
-pub(super) const INPUT: &str = "$ cd /
+pub(crate) const INPUT: &str = "$ cd /
 $ ls
 dir blrnnv
 dir ctfjwl
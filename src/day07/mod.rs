@@ -1,16 +1,20 @@
 use self::input::INPUT;
+use crate::{
+    days::Solution,
+    scanner::{ParseError, ScanError, ScanErrorKind, Scanner},
+};
 use anyhow::{anyhow, Result};
 use std::{
     cell::RefCell,
     collections::{hash_map::DefaultHasher, HashSet},
     hash::{Hash, Hasher},
-    num::ParseIntError,
     rc::Rc,
     str::FromStr,
     time::Duration,
 };
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
@@ -21,13 +25,22 @@ struct File {
     size: usize,
 }
 
+/// The rest of the line as a name, which can't be empty.
+fn scan_name(scanner: &mut Scanner) -> Result<String, ScanError> {
+    if scanner.is_at_line_end() {
+        return Err(scanner.error(ScanErrorKind::Unexpected("end of line".to_owned())));
+    }
+    Ok(scanner.rest_of_line().to_owned())
+}
+
 impl FromStr for File {
-    type Err = ParseIntError;
+    type Err = ScanError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split_whitespace();
-        let size = tokens.next().unwrap().parse::<usize>()?;
-        let name = tokens.next().unwrap().to_owned();
+        let mut scanner = Scanner::new(s);
+        let size = scanner.unsigned::<usize>()?;
+        scanner.expect_literal(" ")?;
+        let name = scan_name(&mut scanner)?;
         Ok(Self { name, size })
     }
 }
@@ -45,17 +58,12 @@ pub(crate) struct Folder {
 type FolderPtr = Rc<RefCell<Folder>>;
 
 impl FromStr for Folder {
-    type Err = anyhow::Error;
+    type Err = ScanError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split_whitespace();
-        let label = tokens.next().unwrap();
-        if label != "dir" {
-            Err(anyhow!("String is not labelled as a directory"))
-        } else {
-            let name = tokens.next().unwrap().to_owned();
-            Ok(Self::new(name, None))
-        }
+        let mut scanner = Scanner::new(s);
+        scanner.expect_literal("dir ")?;
+        Ok(Self::new(scan_name(&mut scanner)?, None))
     }
 }
 
@@ -96,11 +104,12 @@ impl Hash for Item {
 }
 
 impl FromStr for Item {
-    type Err = anyhow::Error;
+    type Err = ScanError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(file) = s.parse::<File>() {
-            Ok(Self::File(file))
+        // Files start with their size.
+        if s.starts_with(|c: char| c.is_ascii_digit()) {
+            Ok(Self::File(s.parse::<File>()?))
         } else {
             Ok(Self::Folder(Rc::new(RefCell::new(s.parse::<Folder>()?))))
         }
@@ -129,7 +138,7 @@ enum ChangeDirectoryTarget {
 }
 
 impl FromStr for ChangeDirectoryTarget {
-    type Err = anyhow::Error;
+    type Err = ScanError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
@@ -147,59 +156,76 @@ enum Command {
 }
 
 impl FromStr for Command {
-    type Err = anyhow::Error;
+    type Err = ScanError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split_whitespace();
-        let prompt = tokens.next().unwrap();
-        if prompt != "$" {
-            Err(anyhow!("No command prompt"))
+        let mut scanner = Scanner::new(s);
+        scanner.expect_literal("$ ")?;
+        if scanner.eat_literal("cd ") {
+            Ok(Self::ChangeDirectory(
+                scan_name(&mut scanner)?.parse::<ChangeDirectoryTarget>()?,
+            ))
+        } else if scanner.eat_literal("ls") {
+            scanner.end_of_line()?;
+            Ok(Self::List)
         } else {
-            let command = tokens.next().unwrap();
-            match command {
-                "cd" => Ok(Self::ChangeDirectory(
-                    tokens.next().unwrap().parse::<ChangeDirectoryTarget>()?,
-                )),
-                "ls" => Ok(Self::List),
-                unrecognized_command => {
-                    Err(anyhow!("Unrecognized command: {unrecognized_command}"))
-                }
-            }
+            Err(scanner.error(ScanErrorKind::Unexpected(format!("command {:?}", &s[2..]))))
         }
     }
 }
 
 pub(crate) const ROOT: &str = "/";
 
-fn deduce_file_system_structure(input: &str) -> FolderPtr {
+fn deduce_file_system_structure(input: &str) -> Result<FolderPtr, ScanError> {
     let root = Rc::new(RefCell::new(Folder::new(ROOT.to_owned(), None)));
     let mut current_folder = root.clone();
-    for line in input.lines() {
-        if let Ok(Command::ChangeDirectory(target)) = line.parse::<Command>() {
+    for (index, line) in input.lines().enumerate() {
+        let place = |error: ScanError| error.offset(index, 0);
+        if line.starts_with('$') {
+            let Command::ChangeDirectory(target) = line.parse::<Command>().map_err(place)? else {
+                // Listings are recognised by not being commands.
+                continue;
+            };
+            // The target follows "$ cd ".
+            let target_error = |what: String| {
+                place(ScanError {
+                    line: 1,
+                    column: 6,
+                    kind: ScanErrorKind::Unexpected(what),
+                })
+            };
             match target {
                 ChangeDirectoryTarget::In(sub_folder) => {
                     let sub_folder_id = make_id(&sub_folder);
-                    let temp;
-                    if let Item::Folder(new_folder) = current_folder
+                    let found = current_folder
                         .borrow()
                         .items
                         .iter()
                         .find(|item| item.make_id() == sub_folder_id)
-                        .unwrap()
-                    {
-                        temp = new_folder.clone();
-                    } else {
-                        panic!();
-                    }
-                    current_folder = temp;
+                        .cloned();
+                    current_folder = match found {
+                        Some(Item::Folder(new_folder)) => new_folder,
+                        Some(Item::File(_)) => {
+                            return Err(target_error(format!(
+                                "file {sub_folder:?}, expected a directory"
+                            )))
+                        }
+                        None => {
+                            return Err(target_error(format!(
+                                "directory {sub_folder:?}, which hasn't been listed"
+                            )))
+                        }
+                    };
                 }
                 ChangeDirectoryTarget::Out => {
-                    let parent = current_folder.borrow().parent.as_ref().unwrap().clone();
-                    current_folder = parent;
+                    let parent = current_folder.borrow().parent.clone();
+                    current_folder =
+                        parent.ok_or_else(|| target_error("\"..\" at the root".to_owned()))?;
                 }
                 ChangeDirectoryTarget::Root => current_folder = root.clone(),
             }
-        } else if let Ok(item) = line.parse::<Item>() {
+        } else {
+            let item = line.parse::<Item>().map_err(place)?;
             current_folder.borrow_mut().items.insert(item.clone());
 
             if let Item::Folder(sub_folder) = item {
@@ -207,7 +233,7 @@ fn deduce_file_system_structure(input: &str) -> FolderPtr {
             }
         }
     }
-    root
+    Ok(root)
 }
 
 fn inject_folder_sizes(folder: FolderPtr) -> usize {
@@ -227,10 +253,11 @@ fn inject_folder_sizes(folder: FolderPtr) -> usize {
     }
 }
 
-pub(crate) fn deduce_file_system(input: &str) -> FolderPtr {
-    let root = deduce_file_system_structure(input);
+pub(crate) fn deduce_file_system(input: &str) -> Result<FolderPtr, ParseError> {
+    let root =
+        deduce_file_system_structure(input).map_err(|error| ParseError::new(7, input, error))?;
     inject_folder_sizes(root.clone());
-    root
+    Ok(root)
 }
 
 #[cfg(test)]
@@ -266,8 +293,8 @@ fn collect_folders(
     });
 }
 
-fn solve_for(input: &str) -> (usize, usize) {
-    let root = deduce_file_system(input);
+fn solve_for(input: &str) -> Result<(usize, usize)> {
+    let root = deduce_file_system(input)?;
     let mut small_folders = vec![];
     collect_folders(
         root.clone(),
@@ -278,24 +305,43 @@ fn solve_for(input: &str) -> (usize, usize) {
         .iter()
         .fold(0, |current, folder| current + folder.borrow().size.unwrap());
 
-    let unused_space = 70_000_000 - (*root).borrow().size.unwrap();
-    let need_to_free = 30_000_000 - unused_space;
-    let mut large_enough_folders = vec![];
-    collect_folders(
-        root,
-        &|folder: &Folder| folder.size.unwrap() >= need_to_free,
-        &mut large_enough_folders,
-    );
-    let best_folder = large_enough_folders
-        .iter()
-        .min_by_key(|folder| folder.borrow().size.unwrap())
-        .unwrap();
-    let part2 = best_folder.borrow().size.unwrap();
+    let used_space = (*root).borrow().size.unwrap();
+    let unused_space = 70_000_000_usize
+        .checked_sub(used_space)
+        .ok_or_else(|| anyhow!("The files take up {used_space}, more than the disk's 70000000"))?;
+    // With enough space already free, nothing needs deleting.
+    let need_to_free = 30_000_000_usize.saturating_sub(unused_space);
+    let part2 = if need_to_free == 0 {
+        0
+    } else {
+        let mut large_enough_folders = vec![];
+        collect_folders(
+            root,
+            &|folder: &Folder| folder.size.unwrap() >= need_to_free,
+            &mut large_enough_folders,
+        );
+        let best_folder = large_enough_folders
+            .iter()
+            .min_by_key(|folder| folder.borrow().size.unwrap())
+            .ok_or_else(|| anyhow!("No directory is big enough to free {need_to_free}"))?;
+        let size = best_folder.borrow().size.unwrap();
+        size
+    };
 
-    (part1, part2)
+    Ok((part1, part2))
 }
 
 pub(crate) fn solve() -> (usize, usize, Duration) {
-    let (part1, part2) = solve_for(INPUT);
-    (part1, part2, Duration::new(0, 0))
+    match solve_for(INPUT) {
+        Ok((part1, part2)) => (part1, part2, Duration::new(0, 0)),
+        Err(error) => {
+            println!("day 7 error: {}", error);
+            (0, 0, Duration::new(0, 0))
+        }
+    }
+}
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, Duration::new(0, 0)))
 }
@@ -1,4 +1,4 @@
-use super::{input, solve_for};
+use super::{generate::mean_file_size, input, solve_for};
 use crate::day07::{deduce_file_system, find_folder, ROOT};
 
 const INPUT: &str = "$ cd /
@@ -27,7 +27,7 @@ $ ls
 
 #[test]
 fn folder_sizes() {
-    let root = deduce_file_system(INPUT);
+    let root = deduce_file_system(INPUT).unwrap();
     assert_eq!(584, (*find_folder(root.clone(), "e").unwrap()).borrow().size.unwrap());
     assert_eq!(94853, (*find_folder(root.clone(), "a").unwrap()).borrow().size.unwrap());
     assert_eq!(24933642, (*find_folder(root.clone(), "d").unwrap()).borrow().size.unwrap());
@@ -36,12 +36,46 @@ fn folder_sizes() {
 
 #[test]
 fn part1() {
-    assert_eq!(95437, solve_for(INPUT).0);
-    assert_eq!(1297159, solve_for(input::INPUT).0);
+    assert_eq!(95437, solve_for(INPUT).unwrap().0);
+    assert_eq!(1297159, solve_for(input::INPUT).unwrap().0);
 }
 
 #[test]
 fn part2() {
-    assert_eq!(24933642, solve_for(INPUT).1);
-    assert_eq!(3866390, solve_for(input::INPUT).1);
+    assert_eq!(24933642, solve_for(INPUT).unwrap().1);
+    assert_eq!(3866390, solve_for(input::INPUT).unwrap().1);
+}
+
+#[test]
+fn roomy_and_overfull_disks() {
+    // Enough space is free already, so nothing needs deleting.
+    assert_eq!((0, 0), solve_for("$ cd /\n$ ls\n").unwrap());
+    assert_eq!(
+        "The files take up 70000001, more than the disk's 70000000",
+        solve_for("$ cd /\n$ ls\n70000001 a")
+            .unwrap_err()
+            .to_string()
+    );
+}
+
+#[test]
+fn generated_file_sizes() {
+    assert_eq!(68, mean_file_size(200 * 1000));
+    // Files never get smaller than a byte, however big the tree.
+    assert_eq!(1, mean_file_size(200 * 68_750));
+    assert_eq!(1, mean_file_size(usize::MAX / 4));
+    assert_eq!(13_750_000, mean_file_size(0));
+}
+
+#[test]
+fn bad_directories() {
+    let error = |input: &str| deduce_file_system(input).unwrap_err().to_string();
+    assert_eq!(
+        "day 7, line 2, column 6: unexpected directory \"a\", which hasn't been listed\n  $ cd a\n       ^",
+        error("$ cd /\n$ cd a")
+    );
+    assert!(error("$ ls\n12 a\n$ cd a")
+        .starts_with("day 7, line 3, column 6: unexpected file \"a\", expected a directory"));
+    assert!(error("$ cd /\n$ ls\ndir a\n$ cd a\n$ cd ..\n$ cd ..")
+        .starts_with("day 7, line 6, column 6: unexpected \"..\" at the root"));
 }
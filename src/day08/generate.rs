@@ -0,0 +1,12 @@
+use crate::generate::Rng;
+
+/// A square forest with about 99x99 trees per unit of scale.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let side = (99.0 * (scale as f64).sqrt()).round() as usize;
+    let mut input = String::new();
+    for _ in 0..side {
+        input.extend((0..side).map(|_| (b'0' + rng.below(10) as u8) as char));
+        input.push('\n');
+    }
+    input
+}
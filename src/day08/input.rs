@@ -1,4 +1,4 @@
-pub(super) const INPUT: &str =
+pub(crate) const INPUT: &str =
 "102200120221123133322123221134224301040024344534431245323303212210030001244234132301200020310012011
 210101220131232022104432220132221131234351145114524135253112332342312410211104233102330122201111010
 002220112233222031400442434031011023425412431121343221233533224144034042201244323431032200232101012
@@ -1,211 +1,122 @@
+use crate::{
+    days::Solution,
+    geometry::{Direction, Point},
+    grid::Grid,
+    scanner::ParseError,
+    visualize::{Frame, Pixel, Player, Rgb},
+};
+use anyhow::Result;
 use input::INPUT;
 use std::{
-    str::FromStr,
+    iter,
     time::{Duration, Instant},
 };
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
 
-struct HeightMap {
-    width: usize,
-    height: usize,
-    data: Vec<u8>,
-}
-
-impl FromStr for HeightMap {
-    type Err = anyhow::Error;
+type HeightMap = Grid<u8>;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            width: s.lines().next().unwrap().chars().count(),
-            height: s.lines().count(),
-            data: s
-                .lines()
-                .flat_map(|line| line.chars().map(|c| c.to_digit(10).unwrap() as u8))
-                .collect::<Vec<_>>(),
-        })
+/// Mark the trees in 'line' that can be seen from its start, looking along it.
+fn mark_visible<'a>(line: impl Iterator<Item = (Point<usize>, &'a u8)>, visible: &mut Grid<bool>) {
+    let mut highest = None;
+    for (point, &height) in line {
+        if highest.is_none_or(|highest| height > highest) {
+            visible[point] = true;
+            highest = Some(height);
+        }
+        if height == 9 {
+            break;
+        }
     }
 }
 
-impl HeightMap {
-    fn index(&self, x: usize, y: usize) -> usize {
-        x + y * self.width
-    }
-
-    fn get(&self, x: usize, y: usize) -> u8 {
-        self.data[self.index(x, y)]
-    }
+/// The trees as their heights, coloured by 'color'.
+fn picture(height_map: &HeightMap, color: impl Fn(Point<usize>) -> Rgb) -> Frame {
+    Grid::from_fn(height_map.width(), height_map.height(), |point| {
+        Pixel::new(char::from(b'0' + height_map[point]), color(point))
+    })
+}
 
-    /// Compute visibility of elements starting at 'start' and proceeding from there with 'inner' iterations
-    /// within an 'outer' loop. Write results into 'vis_map'.
-    fn compute_vis(
-        &self,
-        start: usize,
-        outer_stride: impl Fn(usize) -> usize,
-        outer_end: usize,
-        inner_stride: impl Fn(usize) -> usize,
-        inner_iteration_count: usize,
-        vis_map: &mut [bool],
-    ) {
-        let mut i = start;
-        while i != outer_end {
-            let mut highest = self.data[i];
-            vis_map[i] = true;
-            let mut j = inner_stride(i);
-            for _ in 0..inner_iteration_count {
-                let height = self.data[j];
-                if height > highest {
-                    vis_map[j] = true;
-                    highest = height;
-                }
-                if highest == 9 {
-                    break;
+fn part1(height_map: &HeightMap, player: &mut Player) -> usize {
+    let mut visible = height_map.map(|_| false);
+    let (width, height) = (height_map.width(), height_map.height());
+    // Look in from every tree on the edge.
+    let edges = (0..width)
+        .map(|x| (Point::new(x, 0), Direction::Down))
+        .chain((0..width).map(|x| (Point::new(x, height - 1), Direction::Up)))
+        .chain((0..height).map(|y| (Point::new(0, y), Direction::Right)))
+        .chain((0..height).map(|y| (Point::new(width - 1, y), Direction::Left)));
+    for (start, direction) in edges {
+        let line = iter::once((start, &height_map[start])).chain(height_map.ray(start, direction));
+        mark_visible(line, &mut visible);
+        player.show(&|| {
+            picture(height_map, |point| {
+                if visible[point] {
+                    Rgb::YELLOW
+                } else {
+                    Rgb::GREEN.blend(Rgb::BLACK, 0.6)
                 }
-                j = inner_stride(j);
-            }
-            i = outer_stride(i);
-        }
+            })
+        });
     }
-}
-
-fn part1(height_map: &HeightMap) -> usize {
-    let mut vis_map = vec![false; height_map.data.len()];
 
-    // Columns, top to bottom.
-    height_map.compute_vis(
-        height_map.index(0, 0),
-        |index| index + 1,
-        height_map.width - 1,
-        |index| index + height_map.width,
-        height_map.height - 2,
-        &mut vis_map,
-    );
-
-    // Columns, bottom to top.
-    height_map.compute_vis(
-        height_map.index(0, height_map.height - 1),
-        |index| index + 1,
-        height_map.index(height_map.width - 1, height_map.height - 1),
-        |index| index - height_map.width,
-        height_map.height - 2,
-        &mut vis_map,
-    );
-
-    // Rows, left to right.
-    height_map.compute_vis(
-        height_map.index(0, 0),
-        |index| index + height_map.width,
-        height_map.index(0, height_map.height - 1),
-        |index| index + 1,
-        height_map.width - 2,
-        &mut vis_map,
-    );
-
-    // Rows, right to left.
-    height_map.compute_vis(
-        height_map.index(height_map.width - 1, 0),
-        |index| index + height_map.width,
-        height_map.index(height_map.width - 1, height_map.height - 1),
-        |index| index - 1,
-        height_map.width - 2,
-        &mut vis_map,
-    );
-
-    // Corners.
-    vis_map[height_map.index(0, 0)] = true;
-    vis_map[height_map.index(0, height_map.height - 1)] = true;
-    vis_map[height_map.index(height_map.width - 1, 0)] = true;
-    vis_map[height_map.index(height_map.width - 1, height_map.height - 1)] = true;
-
-    // Visualize.
-    // for y in 0..height_map.height {
-    //     for x in 0..height_map.width {
-    //         print!(
-    //             "{} ",
-    //             if vis_map[height_map.index(x, y)] {
-    //                 '.'
-    //             } else {
-    //                 ' '
-    //             }
-    //         );
-    //     }
-    //     println!();
-    // }
-
-    vis_map.iter().filter(|&&vis| vis).count()
-}
-
-fn scenic_score_in_range(
-    height: u8,
-    range: impl Iterator<Item = usize>,
-    range_value: impl Fn(usize) -> u8,
-) -> usize {
-    let mut blocked = false;
-    range
-        .take_while(|&a| {
-            let candidate_height = range_value(a);
-            if candidate_height >= height {
-                blocked = true;
-            }
-            !blocked && height > candidate_height
-        })
-        .count()
-        + usize::from(blocked)
+    visible.iter().filter(|(_, visible)| **visible).count()
 }
 
-fn part2(height_map: &HeightMap) -> usize {
-    let scenic_score = |x, y| -> usize {
-        let height = height_map.get(x, y);
-        let east = if x == height_map.width - 1 {
-            0
-        } else {
-            scenic_score_in_range(height, x + 1..height_map.width, |x| height_map.get(x, y))
-        };
-        let west = if x == 0 {
-            0
-        } else {
-            scenic_score_in_range(height, (0..x).rev(), |x| height_map.get(x, y))
-        };
-        let south = if y == height_map.height - 1 {
-            0
-        } else {
-            scenic_score_in_range(height, y + 1..height_map.height, |y| height_map.get(x, y))
-        };
-        let north = if y == 0 {
-            0
-        } else {
-            scenic_score_in_range(height, (0..y).rev(), |y| height_map.get(x, y))
-        };
-        east * west * north * south
-    };
-    let mut scenic_map = vec![0; height_map.data.len()];
-    for x in 0..height_map.width {
-        for y in 0..height_map.height {
-            scenic_map[height_map.index(x, y)] = scenic_score(x, y);
+/// How many trees can be seen from 'point' looking in 'direction', counting the one that blocks
+/// the view.
+fn viewing_distance(height_map: &HeightMap, point: Point<usize>, direction: Direction) -> usize {
+    let height = height_map[point];
+    let mut trees = 0;
+    for (_, &tree) in height_map.ray(point, direction) {
+        trees += 1;
+        if tree >= height {
+            break;
         }
     }
+    trees
+}
 
-    // Visualize.
-    // for y in 0..height_map.height {
-    //     for x in 0..height_map.width {
-    //         print!("{} ", scenic_map[height_map.index(x, y)]);
-    //     }
-    //     println!();
-    // }
-
-    *scenic_map.iter().max().unwrap()
+fn part2(height_map: &HeightMap, player: &mut Player) -> usize {
+    let scenic_map = Grid::from_fn(height_map.width(), height_map.height(), |point| {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| viewing_distance(height_map, point, direction))
+            .product::<usize>()
+    });
+
+    let best = scenic_map.iter().map(|(_, score)| *score).max().unwrap();
+    // The more scenic, the brighter.
+    player.show(&|| {
+        picture(height_map, |point| {
+            Rgb::BLACK.blend(Rgb::GREEN, scenic_map[point] as f64 / best.max(1) as f64)
+        })
+    });
+    best
 }
 
-fn solve_for(input: &str) -> (usize, usize, Duration) {
+fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
     let timer = Instant::now();
-    let height_map = input.parse::<HeightMap>().unwrap();
+    let height_map = Grid::parse(input, |c| c.to_digit(10).map(|digit| digit as u8))
+        .map_err(|error| ParseError::new(8, input, error))?;
     let parse_duration = timer.elapsed();
-    (part1(&height_map), part2(&height_map), parse_duration)
+    let mut player = Player::new("day08");
+    Ok((
+        part1(&height_map, &mut player),
+        part2(&height_map, &mut player),
+        parse_duration,
+    ))
 }
 
 pub(crate) fn solve() -> (usize, usize, Duration) {
-    solve_for(INPUT)
+    solve_for(INPUT).unwrap()
+}
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
 }
@@ -9,12 +9,21 @@ const INPUT: &str =
 
 #[test]
 fn part1() {
-    assert_eq!(21, solve_for(INPUT).0);
-    assert_eq!(1829, solve_for(input::INPUT).0);
+    assert_eq!(21, solve_for(INPUT).unwrap().0);
+    assert_eq!(1829, solve_for(input::INPUT).unwrap().0);
 }
 
 #[test]
 fn part2() {
-    assert_eq!(8, solve_for(INPUT).1);
-    assert_eq!(291840, solve_for(input::INPUT).1);  
+    assert_eq!(8, solve_for(INPUT).unwrap().1);
+    assert_eq!(291840, solve_for(input::INPUT).unwrap().1);  
+}
+
+#[test]
+fn parse_error() {
+    let error = solve_for("123\n4x6\n").unwrap_err().to_string();
+    assert!(
+        error.starts_with("day 8, line 2, column 2: unexpected 'x'"),
+        "{error}"
+    );
 }
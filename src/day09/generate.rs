@@ -0,0 +1,11 @@
+use crate::generate::Rng;
+
+/// 2000 motions per unit of scale.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..2000 * scale {
+        let direction = rng.choose(&['R', 'L', 'U', 'D']);
+        input.push_str(&format!("{direction} {}\n", rng.range(1, 20)));
+    }
+    input
+}
@@ -1,4 +1,4 @@
-pub(super) const INPUT: &str = "L 2
+pub(crate) const INPUT: &str = "L 2
 D 2
 U 1
 R 1
@@ -1,110 +1,48 @@
 use self::input::INPUT;
+use crate::{
+    days::Solution,
+    geometry::{Bounds, Direction, Point, Vector},
+    grid::Grid,
+    scanner::{ParseError, ScanError, ScanErrorKind, Scanner},
+    visualize::{Frame, Pixel, Player, Rgb},
+};
+use anyhow::Result;
 use std::{
     collections::HashSet,
-    num::ParseIntError,
-    ops::{Add, AddAssign, Sub},
-    str::FromStr,
     time::{Duration, Instant},
 };
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl Point {
-    fn manhattan_distance(&self) -> usize {
-        self.x.unsigned_abs() as usize + self.y.unsigned_abs() as usize
-    }
-}
-
-impl Add<Direction> for Point {
-    type Output = Self;
-
-    fn add(self, direction: Direction) -> Self::Output {
-        match direction {
-            Direction::Right => Self {
-                x: self.x + 1,
-                y: self.y,
-            },
-            Direction::Left => Self {
-                x: self.x - 1,
-                y: self.y,
-            },
-            Direction::Up => Self {
-                x: self.x,
-                y: self.y - 1,
-            },
-            Direction::Down => Self {
-                x: self.x,
-                y: self.y + 1,
-            },
-        }
-    }
-}
-
-impl AddAssign<Direction> for Point {
-    fn add_assign(&mut self, direction: Direction) {
-        match direction {
-            Direction::Right => self.x += 1,
-            Direction::Left => self.x -= 1,
-            Direction::Up => self.y -= 1,
-            Direction::Down => self.y += 1,
-        }
-    }
-}
-
-impl AddAssign for Point {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-    }
-}
-
-impl Sub for Point {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
-    }
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Direction {
-    Right,
-    Left,
-    Up,
-    Down,
-}
-
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct Motion {
     direction: Direction,
     distance: usize,
 }
 
-impl FromStr for Motion {
-    type Err = ParseIntError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split_whitespace();
-        let direction = match tokens.next().unwrap() {
-            "R" => Direction::Right,
-            "L" => Direction::Left,
-            "U" => Direction::Up,
-            "D" => Direction::Down,
-            _ => panic!(),
+impl Motion {
+    fn scan(scanner: &mut Scanner) -> Result<Self, ScanError> {
+        let direction_column = scanner.column();
+        let direction = match scanner.char()? {
+            'R' => Direction::Right,
+            'L' => Direction::Left,
+            'U' => Direction::Up,
+            'D' => Direction::Down,
+            unknown => {
+                return Err(ScanError {
+                    line: scanner.line(),
+                    column: direction_column,
+                    kind: ScanErrorKind::Unexpected(format!("direction {unknown:?}")),
+                })
+            }
         };
-        let distance = tokens.next().unwrap().parse::<usize>()?;
+        scanner.expect_literal(" ")?;
+        let distance = scanner.unsigned::<usize>()?;
+        scanner.end_of_line()?;
         Ok(Self {
             direction,
             distance,
@@ -112,31 +50,46 @@ impl FromStr for Motion {
     }
 }
 
+fn scan_motions(input: &str) -> Result<Vec<Motion>, ScanError> {
+    let mut motions = vec![];
+    let mut scanner = Scanner::new(input);
+    while !scanner.is_at_end() {
+        motions.push(Motion::scan(&mut scanner)?);
+    }
+    Ok(motions)
+}
+
 fn move_knot(head: &Point, knot: &mut Point) -> bool {
+    // Touching, even diagonally, is close enough.
     let delta = *head - *knot;
-    let manhattan_distance = delta.manhattan_distance();
-    let close_enough =
-        manhattan_distance < 2 || (manhattan_distance == 2 && delta.x != 0 && delta.y != 0);
-    if !close_enough {
-        *knot += Point {
-            x: delta.x.clamp(-1, 1),
-            y: delta.y.clamp(-1, 1),
-        };
+    if delta.chebyshev_length() > 1 {
+        *knot += delta.signum();
         return true;
     }
     false
 }
 
-fn solve_for(input: &str) -> (usize, usize, Duration) {
+/// The rope and where its tail has been. The knots follow the head, so the head's bounds hold
+/// them all.
+fn picture(bounds: &Bounds, knots: &[Point], visited: &HashSet<Point>) -> Frame {
+    Grid::from_fn(bounds.width() as usize, bounds.height() as usize, |cell| {
+        let point = bounds.min + Vector::new(cell.x as i32, cell.y as i32);
+        match knots.iter().position(|knot| *knot == point) {
+            Some(0) => Pixel::new('H', Rgb::RED),
+            Some(index) => Pixel::new(char::from(b'0' + index as u8), Rgb::WHITE),
+            None if visited.contains(&point) => Pixel::new('#', Rgb::GREY),
+            None => Pixel::BLANK,
+        }
+    })
+}
+
+fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
     let timer = Instant::now();
-    let motions = input
-        .lines()
-        .map(|line| line.parse::<Motion>().unwrap())
-        .collect::<Vec<_>>();
+    let motions = scan_motions(input).map_err(|error| ParseError::new(9, input, error))?;
     let parse_duration = timer.elapsed();
 
     // Part 1.
-    let mut head = Point { x: 0, y: 0 };
+    let mut head = Point::new(0, 0);
     let mut tail = head;
     let mut visited = HashSet::new();
     visited.insert(tail);
@@ -151,7 +104,16 @@ fn solve_for(input: &str) -> (usize, usize, Duration) {
     let part1 = visited.len();
 
     // Part 2.
-    let mut knots = vec![Point { x: 0, y: 0 }; 10];
+    let mut player = Player::new("day09");
+    let mut bounds = Bounds::new(Point::new(0, 0));
+    if player.is_on() {
+        let mut head = Point::new(0, 0);
+        for motion in &motions {
+            head += Vector::from(motion.direction) * motion.distance as i32;
+            bounds.include(head);
+        }
+    }
+    let mut knots = vec![Point::new(0, 0); 10];
     visited.clear();
     visited.insert(knots[0]);
     motions.iter().for_each(|motion| {
@@ -166,12 +128,24 @@ fn solve_for(input: &str) -> (usize, usize, Duration) {
                 head = *knot;
             });
         });
+        player.show(&|| picture(&bounds, &knots, &visited));
     });
     let part2 = visited.len();
 
-    (part1, part2, parse_duration)
+    Ok((part1, part2, parse_duration))
 }
 
 pub(crate) fn solve() -> (usize, usize, Duration) {
-    solve_for(INPUT)
+    match solve_for(INPUT) {
+        Ok(solution) => solution,
+        Err(error) => {
+            println!("day 9 error: {}", error);
+            (0, 0, Duration::new(0, 0))
+        }
+    }
+}
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
 }
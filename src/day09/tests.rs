@@ -11,8 +11,8 @@ R 2";
 
 #[test]
 fn part1() {
-    assert_eq!(13, solve_for(INPUT1).0);
-    assert_eq!(5981, solve_for(input::INPUT).0);
+    assert_eq!(13, solve_for(INPUT1).unwrap().0);
+    assert_eq!(5981, solve_for(input::INPUT).unwrap().0);
 }
 
 const INPUT2: &str = "R 5
@@ -26,6 +26,15 @@ U 20";
 
 #[test]
 fn part2() {
-    assert_eq!(36, solve_for(INPUT2).1);
-    assert_eq!(2352, solve_for(input::INPUT).1);
+    assert_eq!(36, solve_for(INPUT2).unwrap().1);
+    assert_eq!(2352, solve_for(input::INPUT).unwrap().1);
+}
+
+#[test]
+fn parse_error() {
+    let error = solve_for("R 4\nX 2\n").unwrap_err().to_string();
+    assert!(
+        error.starts_with("day 9, line 2, column 1: unexpected direction 'X'"),
+        "{error}"
+    );
 }
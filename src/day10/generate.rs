@@ -0,0 +1,23 @@
+use crate::generate::Rng;
+
+/// The puzzle's program runs for the 240 cycles the CRT takes to draw, so scaled programs run for
+/// 'scale' times that, past the end of the screen.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut input = String::new();
+    let mut x = 1i64;
+    let mut cycles = 0;
+    let length = 240 * scale;
+    while cycles < length - 1 {
+        if cycles < length - 2 && rng.chance(2, 3) {
+            // Keep the sprite on screen.
+            let v = rng.range(1 - x, 39 - x);
+            x += v;
+            input.push_str(&format!("addx {v}\n"));
+            cycles += 2;
+        } else {
+            input.push_str("noop\n");
+            cycles += 1;
+        }
+    }
+    input
+}
@@ -1,4 +1,4 @@
-pub(super) const INPUT: &str = "addx 1
+pub(crate) const INPUT: &str = "addx 1
 noop
 addx 2
 noop
@@ -1,12 +1,18 @@
 use self::input::INPUT;
+use crate::{
+    days::Solution,
+    grid::Grid,
+    scanner::{parse_lines, ParseError, ScanError, ScanErrorKind, Scanner},
+    visualize::{Frame, Pixel, Player, Rgb},
+};
 use anyhow::Result;
 use std::{
-    num::ParseIntError,
     str::FromStr,
     time::{Duration, Instant},
 };
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
@@ -27,15 +33,19 @@ impl Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = ParseIntError;
+    type Err = ScanError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split_whitespace();
-        match tokens.next().unwrap() {
-            "addx" => Ok(Self::AddX(tokens.next().unwrap().parse::<i32>()?)),
-            "noop" => Ok(Self::NoOp),
-            _ => panic!(),
-        }
+        let mut scanner = Scanner::new(s);
+        let instruction = if scanner.eat_literal("addx ") {
+            Self::AddX(scanner.signed::<i32>()?)
+        } else if scanner.eat_literal("noop") {
+            Self::NoOp
+        } else {
+            return Err(scanner.error(ScanErrorKind::Unexpected(format!("instruction {s:?}"))));
+        };
+        scanner.end_of_line()?;
+        Ok(instruction)
     }
 }
 
@@ -94,12 +104,25 @@ impl Cpu {
     }
 }
 
+/// The CRT so far, with the beam on the pixel it just drew.
+fn picture(crt: &[char], columns: usize, beam: usize) -> Frame {
+    Grid::from_fn(columns, crt.len() / columns, |point| {
+        let index = point.y * columns + point.x;
+        let color = if index == beam {
+            Rgb::RED
+        } else if crt[index] == '#' {
+            Rgb::YELLOW
+        } else {
+            Rgb::GREY.blend(Rgb::BLACK, 0.5)
+        };
+        Pixel::new(crt[index], color)
+    })
+}
+
 fn solve_for(input: &str) -> Result<(usize, String, Duration)> {
     let timer = Instant::now();
-    let instructions = input
-        .lines()
-        .map(|line| line.parse::<Instruction>())
-        .collect::<Result<Vec<Instruction>, ParseIntError>>()?;
+    let instructions =
+        parse_lines::<Instruction>(input).map_err(|error| ParseError::new(10, input, error))?;
     let parse_duration = timer.elapsed();
 
     const CRT_ROW_COUNT: usize = 6;
@@ -108,11 +131,15 @@ fn solve_for(input: &str) -> Result<(usize, String, Duration)> {
     let mut cpu = Cpu::default();
     let mut observation_cycle = 20;
     const OBSERVATION_PERIOD: usize = 40;
+    // Programs can run for longer than the puzzle's, but only these cycles are observed and only
+    // the first screenful is drawn.
+    const LAST_OBSERVATION_CYCLE: usize = 220;
     let mut signal_strengths = vec![];
     let mut crt = vec!['.'; CRT_COLUMN_COUNT * CRT_ROW_COUNT];
+    let mut player = Player::new("day10");
     cpu.load(instructions);
     loop {
-        if cpu.cycle == observation_cycle {
+        if cpu.cycle == observation_cycle && observation_cycle <= LAST_OBSERVATION_CYCLE {
             signal_strengths.push(cpu.signal_strength());
             observation_cycle += OBSERVATION_PERIOD;
         }
@@ -122,11 +149,15 @@ fn solve_for(input: &str) -> Result<(usize, String, Duration)> {
         if !cpu.tick() {
             break;
         }
+        if cpu.cycle > crt.len() {
+            continue;
+        }
 
         let crt_column = (cpu.cycle - 1) % CRT_COLUMN_COUNT;
         if cpu.x.wrapping_sub(1) <= crt_column && crt_column <= cpu.x.wrapping_add(1) {
             crt[cpu.cycle - 1] = '#';
         }
+        player.show(&|| picture(&crt, CRT_COLUMN_COUNT, cpu.cycle - 1));
     }
 
     let part1 = signal_strengths.iter().sum();
@@ -136,17 +167,23 @@ fn solve_for(input: &str) -> Result<(usize, String, Duration)> {
         part2.extend(&crt[row * CRT_COLUMN_COUNT..(row + 1) * CRT_COLUMN_COUNT]);
         part2.push('\n');
     }
-    println!("{part2}");
-
     Ok((part1, part2, parse_duration))
 }
 
 pub(crate) fn solve() -> (usize, String, Duration) {
     match solve_for(INPUT) {
-        Ok(solution) => solution,
+        Ok(solution) => {
+            println!("{}", solution.1);
+            solution
+        }
         Err(error) => {
             println!("day 10 error: {}", error);
             (0, "".to_owned(), Duration::new(0, 0))
         }
     }
 }
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}
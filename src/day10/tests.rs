@@ -1,4 +1,5 @@
-use super::{input, solve_for};
+use super::{generate::generate, input, solve_for};
+use crate::generate::Rng;
 
 const INPUT: &str = "addx 15
 addx -11
@@ -176,3 +177,20 @@ fn part2() {
         solve_for(input::INPUT).unwrap().1
     );
 }
+
+#[test]
+fn longer_programs() {
+    // Whatever runs after the screen is drawn changes neither answer.
+    let longer = format!("{INPUT}\n{}", "addx 5\nnoop\n".repeat(100));
+    let (part1, part2, _) = solve_for(INPUT).unwrap();
+    let (longer_part1, longer_part2, _) = solve_for(&longer).unwrap();
+    assert_eq!((part1, part2), (longer_part1, longer_part2));
+
+    let generated = generate(&mut Rng::new(3), 5);
+    let cycles = generated
+        .lines()
+        .map(|line| if line == "noop" { 1 } else { 2 })
+        .sum::<usize>();
+    assert_eq!(5 * 240 - 1, cycles);
+    assert_eq!(6, solve_for(&generated).unwrap().1.lines().count());
+}
@@ -0,0 +1,27 @@
+use super::input::INPUT;
+use crate::generate::Rng;
+
+/// The puzzle's monkeys, each starting with 'scale' copies of its items.
+///
+/// Worry levels are only guaranteed not to overflow for the items the puzzle hands out, so rather
+/// than inventing items this repeats them.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let monkeys = INPUT
+        .split("\n\n")
+        .map(|monkey| {
+            monkey
+                .lines()
+                .map(|line| match line.split_once("Starting items: ") {
+                    Some((indent, items)) => {
+                        let mut copies = vec![items; scale];
+                        rng.shuffle(&mut copies);
+                        format!("{indent}Starting items: {}", copies.join(", "))
+                    }
+                    None => line.to_owned(),
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>();
+    monkeys.join("\n\n")
+}
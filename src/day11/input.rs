@@ -1,4 +1,4 @@
-pub(super) const INPUT: &str = "Monkey 0:
+pub(crate) const INPUT: &str = "Monkey 0:
   Starting items: 61
   Operation: new = old * 11
   Test: divisible by 5
@@ -1,14 +1,18 @@
 use self::input::INPUT;
-use anyhow::{anyhow, Result};
+use crate::{
+    days::Solution,
+    scanner::{ParseError, ScanError, ScanErrorKind, Scanner},
+};
+use anyhow::Result;
 use std::{
     mem::swap,
-    num::ParseIntError,
     ops::{Deref, DerefMut},
     str::FromStr,
     time::{Duration, Instant},
 };
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
@@ -29,13 +33,6 @@ impl DerefMut for Worry {
     }
 }
 
-impl FromStr for Worry {
-    type Err = ParseIntError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.parse::<usize>()?))
-    }
-}
-
 type Item = Worry;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -54,13 +51,6 @@ impl DerefMut for MonkeyIndex {
     }
 }
 
-impl FromStr for MonkeyIndex {
-    type Err = ParseIntError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.parse::<usize>()?))
-    }
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Operation {
     Add(Worry),
@@ -68,23 +58,22 @@ enum Operation {
     Square,
 }
 
-impl FromStr for Operation {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split(':').nth(1).unwrap().split_whitespace().skip(3);
-        match tokens.next().unwrap() {
-            "+" => Ok(Self::Add(tokens.next().unwrap().parse::<Worry>()?)),
-            "*" => Ok(if let Ok(worry) = tokens.next().unwrap().parse::<Worry>() {
-                Self::Mul(worry)
-            } else {
-                Self::Square
-            }),
-            unhandled_op => Err(anyhow!("Unhandled op {unhandled_op}")),
-        }
+impl Operation {
+    fn scan(scanner: &mut Scanner) -> Result<Self, ScanError> {
+        scanner.skip_spaces();
+        scanner.expect_literal("Operation: new = old ")?;
+        let operation = if scanner.eat_literal("+ ") {
+            Self::Add(Worry(scanner.unsigned()?))
+        } else if scanner.eat_literal("* old") {
+            Self::Square
+        } else {
+            scanner.expect_literal("* ")?;
+            Self::Mul(Worry(scanner.unsigned()?))
+        };
+        scanner.next_line()?;
+        Ok(operation)
     }
-}
 
-impl Operation {
     fn apply(&self, item: &mut Item) {
         let current = **item;
         *item = match self {
@@ -102,40 +91,38 @@ struct Test {
     if_false: MonkeyIndex,
 }
 
-impl FromStr for Test {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut lines = s.lines();
-        let divisible_by = lines
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .last()
-            .unwrap()
-            .parse::<usize>()?;
-        let if_true = lines
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .last()
-            .unwrap()
-            .parse::<MonkeyIndex>()?;
-        let if_false = lines
-            .next()
-            .unwrap()
-            .split_whitespace()
-            .last()
-            .unwrap()
-            .parse::<MonkeyIndex>()?;
-        Ok(Self {
-            divisible_by,
-            if_true,
-            if_false,
-        })
-    }
-}
+/// Where a monkey's throw targets are, as the line and column of each number, so a target that
+/// turns out not to exist once every monkey has been read can be placed.
+type TargetPlaces = [(usize, usize); 2];
 
 impl Test {
+    fn scan(scanner: &mut Scanner) -> Result<(Self, TargetPlaces), ScanError> {
+        // Only the last line of a monkey can end the input.
+        let mut line = |literal, last| -> Result<(usize, (usize, usize)), ScanError> {
+            scanner.skip_spaces();
+            scanner.expect_literal(literal)?;
+            let place = (scanner.line(), scanner.column());
+            let value = scanner.unsigned()?;
+            if last {
+                scanner.end_of_line()?;
+            } else {
+                scanner.next_line()?;
+            }
+            Ok((value, place))
+        };
+        let (divisible_by, _) = line("Test: divisible by ", false)?;
+        let (if_true, if_true_place) = line("If true: throw to monkey ", false)?;
+        let (if_false, if_false_place) = line("If false: throw to monkey ", true)?;
+        Ok((
+            Self {
+                divisible_by,
+                if_true: MonkeyIndex(if_true),
+                if_false: MonkeyIndex(if_false),
+            },
+            [if_true_place, if_false_place],
+        ))
+    }
+
     fn apply(&self, item: &Item) -> MonkeyIndex {
         if **item % self.divisible_by == 0 {
             self.if_true
@@ -150,44 +137,85 @@ struct Monkey {
     items: Vec<Item>,
     operation: Operation,
     test: Test,
+    target_places: TargetPlaces,
     inspection_count: usize,
 }
 
 impl FromStr for Monkey {
-    type Err = anyhow::Error;
+    type Err = ScanError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut lines = s.lines().skip(1);
-        let items = lines
-            .next()
-            .unwrap()
-            .split(':')
-            .nth(1)
-            .unwrap()
-            .split(',')
-            .map(|item| item.trim().parse::<Item>())
-            .collect::<Result<Vec<Item>, ParseIntError>>()?;
-        let operation = lines.next().unwrap().parse::<Operation>()?;
-        let mut test_text = String::new();
-        (0..3).for_each(|_| {
-            test_text.push_str(lines.next().unwrap());
-            test_text.push('\n');
-        });
-        let test = test_text.parse::<Test>()?;
+        let mut scanner = Scanner::new(s);
+        scanner.expect_literal("Monkey ")?;
+        scanner.unsigned::<usize>()?;
+        scanner.expect_literal(":")?;
+        scanner.next_line()?;
+        scanner.skip_spaces();
+        scanner.expect_literal("Starting items: ")?;
+        let mut items = vec![Worry(scanner.unsigned()?)];
+        while scanner.eat_literal(", ") {
+            items.push(Worry(scanner.unsigned()?));
+        }
+        scanner.next_line()?;
+        let operation = Operation::scan(&mut scanner)?;
+        let (test, target_places) = Test::scan(&mut scanner)?;
+        if !scanner.is_at_end() {
+            return Err(scanner.error(ScanErrorKind::Unexpected(
+                "text after the monkey".to_owned(),
+            )));
+        }
         Ok(Self {
             items,
             operation,
             test,
+            target_places,
             inspection_count: 0,
         })
     }
 }
 
+/// Check that every monkey throws to monkeys that exist.
+fn check_targets(monkeys: &[Monkey]) -> Result<(), ScanError> {
+    for monkey in monkeys {
+        let targets = [monkey.test.if_true, monkey.test.if_false];
+        for (target, (line, column)) in targets.into_iter().zip(monkey.target_places) {
+            if *target >= monkeys.len() {
+                return Err(ScanError {
+                    line,
+                    column,
+                    kind: ScanErrorKind::Unexpected(format!(
+                        "monkey {}, expected 0 to {}",
+                        *target,
+                        monkeys.len() - 1
+                    )),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
 fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
     let timer = Instant::now();
+    // Monkeys are separated by a blank line, so each starts a few lines further down.
+    let mut line = 0;
     let monkeys = input
         .split("\n\n")
-        .map(|monkey| monkey.parse::<Monkey>())
-        .collect::<Result<Vec<Monkey>>>()?;
+        .map(|monkey| {
+            let parsed = monkey
+                .parse::<Monkey>()
+                .map(|mut parsed| {
+                    for place in &mut parsed.target_places {
+                        place.0 += line;
+                    }
+                    parsed
+                })
+                .map_err(|error| error.offset(line, 0));
+            line += monkey.lines().count() + 1;
+            parsed
+        })
+        .collect::<Result<Vec<Monkey>, ScanError>>()
+        .and_then(|monkeys| check_targets(&monkeys).map(|()| monkeys))
+        .map_err(|error| ParseError::new(11, input, error))?;
     let parse_duration = timer.elapsed();
 
     // println!("{monkeys:?}");
@@ -252,3 +280,8 @@ fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
 pub(crate) fn solve() -> (usize, usize, Duration) {
     solve_for(INPUT).unwrap()
 }
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}
@@ -39,3 +39,30 @@ fn part2() {
     assert_eq!(2713310158, solve_for(INPUT).unwrap().1);
     assert_eq!(21553910156, solve_for(input::INPUT).unwrap().1);
 }
+
+#[test]
+fn parse_error() {
+    let input = INPUT.replacen("old + 6", "old - 6", 1);
+    let error = solve_for(&input).unwrap_err().to_string();
+    assert!(
+        error.starts_with("day 11, line 10, column 24: expected \"* \""),
+        "{error}"
+    );
+
+    // A monkey cut short is missing its next line, not the end of its last one.
+    let error = solve_for("Monkey 0:\n\nMonkey 1:").unwrap_err().to_string();
+    assert!(
+        error.starts_with("day 11, line 2, column 1: unexpected end of input"),
+        "{error}"
+    );
+}
+
+#[test]
+fn missing_monkeys() {
+    let input = INPUT.replacen("throw to monkey 3", "throw to monkey 4", 1);
+    let error = solve_for(&input).unwrap_err().to_string();
+    assert!(
+        error.starts_with("day 11, line 6, column 31: unexpected monkey 4, expected 0 to 3"),
+        "{error}"
+    );
+}
@@ -0,0 +1,32 @@
+use crate::generate::Rng;
+
+/// A heightmap with about 41x83 squares per unit of scale.
+///
+/// Elevation climbs from 'a' to 'z' across the columns, with random pits that the path has to go
+/// around. The start is on the left edge and the best signal on the right.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let factor = (scale as f64).sqrt();
+    let height = ((41.0 * factor).round() as usize).max(3);
+    let width = ((83.0 * factor).round() as usize).max(26);
+    let start_row = rng.below(height);
+    let end_row = rng.below(height);
+    let mut input = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let elevation = (x * 26 / width) as u8;
+            let c = if x == 0 && y == start_row {
+                'S'
+            } else if x == width - 1 && y == end_row {
+                'E'
+            } else if 0 < x && x < width - 1 && y % 2 == 1 && rng.chance(1, 8) {
+                // Pits only on odd rows, so the even rows always offer a way through.
+                'a'
+            } else {
+                (b'a' + elevation) as char
+            };
+            input.push(c);
+        }
+        input.push('\n');
+    }
+    input
+}
@@ -1,117 +1,66 @@
+use crate::{days::Solution, geometry::Point, grid::Grid, scanner::ParseError};
 use anyhow::{anyhow, Result};
 use input::INPUT;
 use std::{
+    collections::VecDeque,
     str::FromStr,
     time::{Duration, Instant},
 };
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
 
 struct HeightMap {
-    width: usize,
-    data: Vec<u8>,
-    start: usize,
-    end: usize,
+    grid: Grid<u8>,
+    start: Point<usize>,
+    end: Point<usize>,
 }
 
 impl FromStr for HeightMap {
     type Err = anyhow::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let width = s.lines().next().unwrap().chars().count();
-        let mut data = s.lines().flat_map(|line| line.bytes()).collect::<Vec<_>>();
-        let start = data
-            .iter()
-            .enumerate()
-            .find_map(|(index, c)| if *c == b'S' { Some(index) } else { None })
-            .unwrap();
-        data[start] = b'a';
-        let end = data
-            .iter()
-            .enumerate()
-            .find_map(|(index, c)| if *c == b'E' { Some(index) } else { None })
-            .unwrap();
-        data[end] = b'z';
-        Ok(Self {
-            width,
-            data,
-            start,
-            end,
-        })
+        let mut grid = Grid::parse(s, |c| matches!(c, 'a'..='z' | 'S' | 'E').then_some(c as u8))
+            .map_err(|error| ParseError::new(12, s, error))?;
+        let start = grid
+            .position(|c| *c == b'S')
+            .ok_or_else(|| anyhow!("No start"))?;
+        grid[start] = b'a';
+        let end = grid
+            .position(|c| *c == b'E')
+            .ok_or_else(|| anyhow!("No end"))?;
+        grid[end] = b'z';
+        Ok(Self { grid, start, end })
     }
 }
 
 impl HeightMap {
-    fn xy_from_index(&self, index: usize) -> (usize, usize) {
-        (index % self.width, index / self.width)
-    }
-
-    fn can_traverse_to(&self, from: usize, to: usize) -> bool {
-        if let Some(step) = self.data[to].checked_sub(self.data[from]) {
+    fn can_traverse_to(&self, from: Point<usize>, to: Point<usize>) -> bool {
+        if let Some(step) = self.grid[to].checked_sub(self.grid[from]) {
             step <= 1
         } else {
             true
         }
     }
 
-    fn shortest_path_length(&self, start: usize) -> Result<usize> {
-        let mut visited = vec![false; self.data.len()];
+    fn shortest_path_length(&self, start: Point<usize>) -> Result<usize> {
+        let mut visited = self.grid.map(|_| false);
         visited[start] = true;
-        let mut paths = vec![Path {
-            index: start,
+        let mut paths = VecDeque::from([Path {
+            point: start,
             length: 0,
-        }];
-        while !paths.is_empty() {
-            let path = paths[0];
-
-            paths.remove(0); // TODO bad data structure
-                             // visited[path.index] = true;
-
-            let from = self.xy_from_index(path.index);
+        }]);
+        while let Some(path) = paths.pop_front() {
             let length = path.length + 1;
-
-            let mut add_path = |index: usize| {
-                if !visited[index] && self.can_traverse_to(path.index, index) {
-                    if index == self.end {
-                        return Err(());
+            for point in self.grid.neighbours4(path.point) {
+                if !visited[point] && self.can_traverse_to(path.point, point) {
+                    if point == self.end {
+                        return Ok(length);
                     }
-                    visited[index] = true;
-                    paths.push(Path { index, length });
-                }
-                Ok(())
-            };
-
-            // north
-            if let Some(index) = path.index.checked_sub(self.width) {
-                if add_path(index).is_err() {
-                    return Ok(length);
-                }
-            }
-
-            // west
-            if let Some(index) = path.index.checked_sub(1) {
-                let to = self.xy_from_index(index);
-                // if the y is same
-                if from.1 == to.1 && add_path(index).is_err() {
-                    return Ok(length);
-                }
-            }
-
-            // south
-            let index = path.index + self.width;
-            if index < self.data.len() && add_path(index).is_err() {
-                return Ok(length);
-            }
-
-            // east
-            let index = path.index + 1;
-            if index < self.data.len() {
-                let to = self.xy_from_index(index);
-                // if the y is same
-                if from.1 == to.1 && add_path(index).is_err() {
-                    return Ok(length);
+                    visited[point] = true;
+                    paths.push_back(Path { point, length });
                 }
             }
         }
@@ -121,7 +70,7 @@ impl HeightMap {
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct Path {
-    index: usize,
+    point: Point<usize>,
     length: usize,
 }
 
@@ -133,10 +82,9 @@ fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
     let part1 = height_map.shortest_path_length(height_map.start)?;
 
     let starts = height_map
-        .data
+        .grid
         .iter()
-        .enumerate()
-        .filter_map(|(index, height)| if *height == b'a' { Some(index) } else { None })
+        .filter_map(|(point, height)| (*height == b'a').then_some(point))
         .collect::<Vec<_>>();
     let part2 = starts
         .iter()
@@ -156,3 +104,8 @@ pub(crate) fn solve() -> (usize, usize, Duration) {
         }
     }
 }
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}
@@ -0,0 +1,22 @@
+use crate::generate::Rng;
+
+/// 150 packet pairs per unit of scale.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let pairs = (0..150 * scale)
+        .map(|_| format!("{}\n{}\n", list(rng, 0), list(rng, 0)))
+        .collect::<Vec<_>>();
+    pairs.join("\n")
+}
+
+fn list(rng: &mut Rng, depth: usize) -> String {
+    let elements = (0..rng.below(6))
+        .map(|_| {
+            if depth < 4 && rng.chance(1, 3) {
+                list(rng, depth + 1)
+            } else {
+                rng.below(11).to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", elements.join(","))
+}
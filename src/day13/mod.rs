@@ -1,18 +1,23 @@
-use anyhow::{anyhow, Result};
+use crate::{
+    days::Solution,
+    scanner::{ParseError, ScanError, ScanErrorKind, Scanner},
+};
+use anyhow::Result;
 use input::INPUT;
 use std::{
     cmp::Ordering,
-    num::ParseIntError,
     str::FromStr,
     time::{Duration, Instant},
 };
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
 
-/// An iterator over a string representing a list that can have lists as elements.
+/// An iterator over a string representing a list that can have lists as elements. Each element
+/// comes with its offset in the string.
 #[derive(Clone, Debug)]
 struct ListIterator<'a> {
     s: &'a str,
@@ -20,16 +25,24 @@ struct ListIterator<'a> {
 }
 
 impl<'a> ListIterator<'a> {
-    fn new(s: &'a str) -> Result<Self> {
-        if !s.starts_with('[') || !s.ends_with(']') {
-            return Err(anyhow!("String is not a list"));
+    fn new(s: &'a str) -> Result<Self, ScanError> {
+        let error = |column, bracket: &str| ScanError {
+            line: 1,
+            column,
+            kind: ScanErrorKind::ExpectedLiteral(bracket.to_owned()),
+        };
+        if !s.starts_with('[') {
+            return Err(error(1, "["));
+        }
+        if s.len() < 2 || !s.ends_with(']') {
+            return Err(error(s.len() + 1, "]"));
         }
         Ok(Self { s, current: 1 })
     }
 }
 
 impl<'a> Iterator for ListIterator<'a> {
-    type Item = &'a str;
+    type Item = (usize, &'a str);
     fn next(&mut self) -> Option<Self::Item> {
         if self.current == self.s.len() - 1 {
             return None;
@@ -37,26 +50,29 @@ impl<'a> Iterator for ListIterator<'a> {
         // From the current position, scan.
         // For every open bracket we encounter, we'll need to encounter a close bracket.
         // But if we find a comma, we're done.
+        // Offsets are in bytes. The element only ever ends at an ASCII comma or the closing
+        // bracket, so the slice lands on character boundaries whatever is in between.
+        let bytes = self.s.as_bytes();
         let mut open = 0;
-        let offset = self.s[self.current..self.s.len() - 1]
-            .chars()
-            .take_while(|c| match c {
-                '[' => {
+        let offset = bytes[self.current..bytes.len() - 1]
+            .iter()
+            .take_while(|byte| match byte {
+                b'[' => {
                     open += 1;
                     true
                 }
-                ']' => {
+                b']' => {
                     open -= 1;
                     true
                 }
-                ',' => open != 0,
+                b',' => open != 0,
                 _ => true,
             })
             .count();
         let new_current = self.current + offset;
-        let element = &self.s[self.current..new_current];
+        let element = (self.current, &self.s[self.current..new_current]);
         self.current = new_current;
-        if self.s.chars().nth(self.current).unwrap() == ',' {
+        if bytes[self.current] == b',' {
             self.current += 1;
         }
         Some(element)
@@ -99,15 +115,27 @@ fn list_iterator() {
     let s = "[1,[2,[3,[4,[5,6,7]]]],8,9]";
     let i = ListIterator::new(s).unwrap();
     assert_eq!(4, i.count());
+
+    let s = "[é,[ü],1]";
+    let i = ListIterator::new(s).unwrap();
+    assert_eq!(vec![(1, "é"), (4, "[ü]"), (9, "1")], i.collect::<Vec<_>>());
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord)]
 struct Int(usize);
 
 impl FromStr for Int {
-    type Err = ParseIntError;
+    type Err = ScanError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.parse::<usize>()?))
+        let mut scanner = Scanner::new(s);
+        let int = Self(scanner.unsigned()?);
+        if !scanner.is_at_end() {
+            return Err(scanner.error(ScanErrorKind::Unexpected(format!(
+                "{:?} in an integer",
+                &s[scanner.column() - 1..]
+            ))));
+        }
+        Ok(int)
     }
 }
 
@@ -115,12 +143,16 @@ impl FromStr for Int {
 struct List(Vec<Packet>);
 
 impl FromStr for List {
-    type Err = anyhow::Error;
+    type Err = ScanError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self(
             ListIterator::new(s)?
-                .map(|element| element.parse::<Packet>())
-                .collect::<Result<Vec<Packet>>>()?,
+                .map(|(offset, element)| {
+                    element
+                        .parse::<Packet>()
+                        .map_err(|error| error.offset(0, offset))
+                })
+                .collect::<Result<Vec<Packet>, ScanError>>()?,
         ))
     }
 }
@@ -150,14 +182,12 @@ enum Packet {
 }
 
 impl FromStr for Packet {
-    type Err = anyhow::Error;
+    type Err = ScanError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(int) = s.parse::<Int>() {
-            Ok(Self::Int(int))
-        } else if let Ok(list) = s.parse::<List>() {
-            Ok(Self::List(list))
+        if s.starts_with('[') {
+            Ok(Self::List(s.parse::<List>()?))
         } else {
-            Err(anyhow!("Not an integer and not a list"))
+            Ok(Self::Int(s.parse::<Int>()?))
         }
     }
 }
@@ -185,15 +215,27 @@ impl Ord for Packet {
 
 fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
     let timer = Instant::now();
+    // Pairs are separated by a blank line, so each starts a few lines further down.
+    let mut line = 0;
     let packet_pairs = input
         .split("\n\n")
         .map(|packet_pair| {
-            let mut lines = packet_pair.lines();
-            let a = lines.next().unwrap().parse::<List>()?;
-            let b = lines.next().unwrap().parse::<List>()?;
-            Ok((a, b))
+            let mut lines = packet_pair.lines().enumerate();
+            let mut packet = || -> Result<List, ScanError> {
+                let (index, text) = lines.next().ok_or(ScanError {
+                    line: line + packet_pair.lines().count() + 1,
+                    column: 1,
+                    kind: ScanErrorKind::UnexpectedEndOfInput,
+                })?;
+                text.parse::<List>()
+                    .map_err(|error| error.offset(line + index, 0))
+            };
+            let pair = (packet()?, packet()?);
+            line += packet_pair.lines().count() + 1;
+            Ok(pair)
         })
-        .collect::<Result<Vec<_>>>()?;
+        .collect::<Result<Vec<_>, ScanError>>()
+        .map_err(|error| ParseError::new(13, input, error))?;
     let parse_duration = timer.elapsed();
 
     // println!("{packet_pairs:?}");
@@ -207,7 +249,7 @@ fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
     let divider_packets = ["[[2]]", "[[6]]"]
         .into_iter()
         .map(|s| s.parse::<List>())
-        .collect::<Result<Vec<_>>>()?;
+        .collect::<Result<Vec<_>, ScanError>>()?;
     let mut packets: Vec<&List> = vec![];
     let (lhs, rhs): (Vec<&List>, Vec<&List>) =
         packet_pairs.iter().map(|(lhs, rhs)| (lhs, rhs)).unzip();
@@ -245,3 +287,8 @@ pub(crate) fn solve() -> (usize, usize, Duration) {
         }
     }
 }
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}
@@ -35,3 +35,23 @@ fn part2() {
     assert_eq!(140, solve_for(INPUT).unwrap().1);
     assert_eq!(22288, solve_for(input::INPUT).unwrap().1);
 }
+
+#[test]
+fn parse_error() {
+    let error = solve_for("[1,2]\n[3]\n\n[4]\n[5,[6,x]]")
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        "day 13, line 5, column 7: expected a digit\n  [5,[6,x]]\n        ^",
+        error
+    );
+}
+
+#[test]
+fn non_ascii() {
+    let error = solve_for("[1]\n[2]\n\n[é]\n[1]").unwrap_err().to_string();
+    assert_eq!(
+        "day 13, line 4, column 2: expected a digit\n  [é]\n   ^",
+        error
+    );
+}
@@ -0,0 +1,29 @@
+use crate::generate::Rng;
+
+/// About 150 rock paths per unit of scale.
+///
+/// The cave gets deeper with scale, up to the 320 rows the solver's grid can hold with the sand
+/// source at x = 500; past that, scale only adds more rock.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let depth = ((170.0 * (scale as f64).sqrt()) as i64).clamp(10, 320);
+    let mut input = String::new();
+    for _ in 0..150 * scale {
+        let mut x = rng.range(500 - depth, 500 + depth);
+        let mut y = rng.range(2, depth);
+        let mut points = vec![format!("{x},{y}")];
+        for segment in 0..rng.range(1, 6) {
+            let length = rng.range(1, 8);
+            if segment % 2 == 0 {
+                x = (x + *rng.choose(&[-length, length])).clamp(500 - depth, 500 + depth);
+            } else {
+                y = (y + *rng.choose(&[-length, length])).clamp(2, depth);
+            }
+            points.push(format!("{x},{y}"));
+        }
+        // Clamping can turn a segment into a point, which isn't a valid path.
+        points.dedup();
+        input.push_str(&points.join(" -> "));
+        input.push('\n');
+    }
+    input
+}
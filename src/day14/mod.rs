@@ -1,115 +1,31 @@
 use anyhow::{anyhow, Result};
-use std::{
-    num::ParseIntError,
-    ops::{Add, AddAssign, Sub},
-    str::FromStr,
-    time::{Duration, Instant},
-};
+use std::time::{Duration, Instant};
 
 use self::input::INPUT;
+use crate::{
+    days::Solution,
+    geometry::{Bounds, Direction, Direction8, Point},
+    grid::Grid,
+    scanner::{ParseError, ScanError, Scanner},
+    visualize::{Frame, Pixel, Player, Rgb},
+};
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl FromStr for Point {
-    type Err = ParseIntError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split(',');
-        let x = tokens.next().unwrap().parse::<usize>()?;
-        let y = tokens.next().unwrap().parse::<usize>()?;
-        Ok(Self { x, y })
-    }
-}
-
-impl Sub for Point {
-    type Output = Vector;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::Output {
-            x: self.x as i32 - rhs.x as i32,
-            y: self.y as i32 - rhs.y as i32,
-        }
-    }
-}
-
-impl AddAssign<Vector> for Point {
-    fn add_assign(&mut self, rhs: Vector) {
-        self.x = (self.x as i32 + rhs.x) as usize;
-        self.y = (self.y as i32 + rhs.y) as usize;
-    }
-}
-
-// TODO any way to do this generically via Into?
-impl AddAssign<Direction> for Point {
-    fn add_assign(&mut self, rhs: Direction) {
-        *self += Vector::from(rhs)
-    }
-}
-
-impl Add<Vector> for Point {
-    type Output = Self;
-    fn add(self, rhs: Vector) -> Self::Output {
-        Self {
-            x: (self.x as i32 + rhs.x) as usize,
-            y: (self.y as i32 + rhs.y) as usize,
-        }
-    }
-}
+const SAND_ENTRY_POINT: Point = Point::new(500, 0);
 
-// TODO any way to do this generically via Into?
-impl Add<Direction> for Point {
-    type Output = Self;
-    fn add(self, rhs: Direction) -> Self::Output {
-        self + Vector::from(rhs)
-    }
-}
+/// Part 2 pours tens of thousands of grains, so only every this many make a frame.
+const FLOOR_FRAME_PERIOD: usize = 100;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct Vector {
-    x: i32,
-    y: i32,
-}
-
-impl From<Direction> for Vector {
-    fn from(direction: Direction) -> Self {
-        match direction {
-            Direction::Up => Self { x: 0, y: -1 },
-            Direction::Down => Self { x: 0, y: 1 },
-            Direction::Left => Self { x: -1, y: 0 },
-            Direction::Right => Self { x: 1, y: 0 },
-        }
-    }
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn from_vector(v: Vector) -> Option<Self> {
-        if v.x < 0 {
-            Some(Self::Left)
-        } else if v.x > 0 {
-            Some(Self::Right)
-        } else if v.y < 0 {
-            Some(Self::Up)
-        } else if v.y > 0 {
-            Some(Self::Down)
-        } else {
-            None
-        }
-    }
+fn scan_point(scanner: &mut Scanner) -> Result<Point, ScanError> {
+    let x = scanner.unsigned::<i32>()?;
+    scanner.expect_literal(",")?;
+    let y = scanner.unsigned::<i32>()?;
+    Ok(Point::new(x, y))
 }
 
 #[derive(Clone, Debug)]
@@ -118,20 +34,29 @@ struct Polyline {
     segments: Vec<(Direction, usize)>,
 }
 
-impl FromStr for Polyline {
-    type Err = anyhow::Error;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut points = s.split(" -> ");
-        let start = points.next().unwrap().parse::<Point>()?;
+impl Polyline {
+    fn scan_points(scanner: &mut Scanner) -> Result<Vec<Point>, ScanError> {
+        let mut points = vec![scan_point(scanner)?];
+        while scanner.eat_literal(" -> ") {
+            points.push(scan_point(scanner)?);
+        }
+        scanner.end_of_line()?;
+        Ok(points)
+    }
+
+    fn from_points(points: &[Point]) -> Result<Self> {
+        let start = *points
+            .first()
+            .ok_or_else(|| anyhow!("A polyline needs at least one point"))?;
         let mut prev = start;
-        let segments = points
-            .map(|point| -> Result<(Direction, usize), anyhow::Error> {
-                let point = point.parse::<Point>()?;
+        let segments = points[1..]
+            .iter()
+            .map(|&point| -> Result<(Direction, usize), anyhow::Error> {
                 let delta = point - prev;
                 prev = point;
                 let direction = Direction::from_vector(delta)
                     .ok_or_else(|| anyhow!("Delta doesn't map to a Direction. Probably zero."))?;
-                let distance = (delta.x.abs() + delta.y.abs()) as usize;
+                let distance = delta.manhattan_length() as usize;
                 Ok((direction, distance))
             })
             .collect::<Result<Vec<(Direction, usize)>, anyhow::Error>>()?;
@@ -139,129 +64,122 @@ impl FromStr for Polyline {
     }
 }
 
-fn simulate(width: usize, height: usize, x_min: usize, grid: &mut [bool]) -> usize {
-    let index = |point: &Point| (point.y * width) + (point.x - x_min);
-    const SAND_ENTRY_POINT: Point = Point { x: 500, y: 0 };
-    let sand_entry_index = index(&SAND_ENTRY_POINT);
+/// Pour sand in at 'entry' until it stops settling, either because it falls off the bottom or
+/// the sides or because it has piled up to the entry. 'on_settled' is told the cave and the count
+/// after each grain. Returns how much settled.
+fn simulate(
+    cave: &mut Grid<bool>,
+    entry: Point<usize>,
+    mut on_settled: impl FnMut(&Grid<bool>, usize),
+) -> usize {
     let mut count = 0;
-    loop {
-        if grid[sand_entry_index] {
-            break;
-        }
-        let mut sand = SAND_ENTRY_POINT;
+    while !cave[entry] {
+        let mut sand = entry;
         let settled = loop {
-            if sand.y == height - 1 {
-                break false;
-            }
-            let below = sand + Direction::Down;
-            if !grid[index(&below)] {
-                sand = below;
-            } else {
-                if sand.x - x_min == 0 {
-                    break false;
-                }
-                let next = below + Direction::Left;
-                if !grid[index(&next)] {
-                    sand = next;
-                } else {
-                    if sand.x - x_min == width - 1 {
-                        break false;
-                    }
-                    let next = below + Direction::Right;
-                    if !grid[index(&next)] {
-                        sand = next;
-                    } else {
-                        break true;
-                    }
-                }
+            let next = [
+                Direction8::Down,
+                Direction8::DownLeft,
+                Direction8::DownRight,
+            ]
+            .into_iter()
+            .map(|direction| cave.step(sand, direction))
+            .find(|next| next.is_none_or(|next| !cave[next]));
+            match next {
+                Some(Some(next)) => sand = next,
+                // Off the edge of the grid.
+                Some(None) => break false,
+                None => break true,
             }
         };
-        if settled {
-            grid[index(&sand)] = true;
-            count += 1;
-        } else {
+        if !settled {
             break;
         }
+        cave[sand] = true;
+        count += 1;
+        on_settled(cave, count);
     }
     count
 }
 
+/// The cave with its rock, the sand that has settled on it and where the sand comes in.
+fn picture(rock: &Grid<bool>, cave: &Grid<bool>, entry: Point<usize>) -> Frame {
+    Grid::from_fn(cave.width(), cave.height(), |point| {
+        if point == entry {
+            Pixel::new('+', Rgb::RED)
+        } else if rock[point] {
+            Pixel::new('#', Rgb::GREY)
+        } else if cave[point] {
+            Pixel::new('o', Rgb::YELLOW)
+        } else {
+            Pixel::BLANK
+        }
+    })
+}
+
+fn scan_point_lists(input: &str) -> Result<Vec<Vec<Point>>, ScanError> {
+    let mut point_lists = vec![];
+    let mut scanner = Scanner::new(input);
+    while !scanner.is_at_end() {
+        point_lists.push(Polyline::scan_points(&mut scanner)?);
+    }
+    Ok(point_lists)
+}
+
 fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
     let timer = Instant::now();
-    let polylines = input
-        .lines()
-        .map(|line| {
-            line.split(" -> ")
-                .map(|point| point.parse::<Point>())
-                .collect::<Result<Vec<_>, ParseIntError>>()
-        })
-        .collect::<Result<Vec<_>, ParseIntError>>()?;
+    let point_lists = scan_point_lists(input).map_err(|error| ParseError::new(14, input, error))?;
+    let polylines = point_lists
+        .iter()
+        .map(|points| Polyline::from_points(points))
+        .collect::<Result<Vec<_>>>()?;
     let parse_duration = timer.elapsed();
 
-    let (x_min, x_max, y_max) = polylines.iter().flatten().fold(
-        (500, 500, 0),
-        |(mut x_min, mut x_max, mut y_max), point| {
-            if point.x < x_min {
-                x_min = point.x;
-            }
-            if x_max < point.x {
-                x_max = point.x;
-            }
-            if y_max < point.y {
-                y_max = point.y;
-            }
-            (x_min, x_max, y_max)
-        },
-    );
-    // Part 1 dimensions.
-    let width = x_max - x_min + 1;
-    let height = y_max + 1;
+    let mut bounds = Bounds::new(SAND_ENTRY_POINT);
+    point_lists
+        .iter()
+        .flatten()
+        .for_each(|point| bounds.include(*point));
+    // Part 1 dimensions. The sand comes in at the top, so the bounds start at y = 0.
+    let width = bounds.width() as usize;
+    let height = bounds.height() as usize;
 
     // Add floor for part 2, and inflate the grid accordingly.
     let floor_depth = height + 2;
     let floor_length = (floor_depth + 1) * 3; // arbitrary multiple just to ensure it is big enough
     let inflation = floor_length - width;
-    let x_min = x_min - inflation / 2;
+    let x_min = bounds.min.x - (inflation / 2) as i32;
     let width = floor_length;
     let height = floor_depth;
 
-    let mut grid = vec![false; width * height];
-
-    let index = |point: &Point| (point.y * width) + (point.x - x_min);
-
-    // TODO double-parsing
-    let polylines = input
-        .lines()
-        .map(|line| line.parse::<Polyline>())
-        .collect::<Result<Vec<_>>>()?;
+    let mut cave = Grid::new(width, height, false);
+    let cell = |point: Point| Point::new((point.x - x_min) as usize, point.y as usize);
 
     polylines.iter().for_each(|polyline| {
         let mut point = polyline.start;
-        grid[index(&point)] = true;
-        // println!("{point:?}");
+        cave[cell(point)] = true;
         polyline.segments.iter().for_each(|(direction, distance)| {
-            // println!("{direction:?} {distance}");
-            let delta = Vector::from(*direction);
             (0..*distance).for_each(|_| {
-                point += delta;
-                // println!("{point:?}");
-                grid[index(&point)] = true;
+                point += *direction;
+                cave[cell(point)] = true;
             });
         });
     });
 
-    let part1 = simulate(width, height, x_min, &mut grid.clone());
+    let entry = cell(SAND_ENTRY_POINT);
+    let mut player = Player::new("day14");
+    let rock = cave.clone();
+    let part1 = simulate(&mut cave.clone(), entry, |cave, _| {
+        player.show(&|| picture(&rock, cave, entry))
+    });
 
-    let floor_start = Point {
-        x: x_min,
-        y: floor_depth - 1,
-    };
-    let start_index = index(&floor_start);
-    grid.iter_mut()
-        .skip(start_index)
-        .take(floor_length)
-        .for_each(|cell| *cell = true);
-    let part2 = simulate(width, height, x_min, &mut grid);
+    cave.row_mut(floor_depth - 1).fill(true);
+    let rock = cave.clone();
+    let part2 = simulate(&mut cave, entry, |cave, count| {
+        if count.is_multiple_of(FLOOR_FRAME_PERIOD) {
+            player.show(&|| picture(&rock, cave, entry));
+        }
+    });
+    player.show(&|| picture(&rock, &cave, entry));
 
     Ok((part1, part2, parse_duration))
 }
@@ -269,3 +187,8 @@ fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {
 pub(crate) fn solve() -> (usize, usize, Duration) {
     solve_for(INPUT).unwrap()
 }
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}
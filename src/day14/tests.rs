@@ -14,3 +14,14 @@ fn part2() {
     assert_eq!(93, solve_for(INPUT).unwrap().1);
     assert_eq!(28594, solve_for(input::INPUT).unwrap().1);
 }
+
+#[test]
+fn parse_error() {
+    let error = solve_for("498,4 -> 498,6\n503,4 -> 502\n")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.starts_with("day 14, line 2, column 13: expected \",\""),
+        "{error}"
+    );
+}
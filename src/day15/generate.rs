@@ -0,0 +1,41 @@
+use crate::generate::Rng;
+
+const AREA: i64 = 4_000_000;
+
+/// About 34 sensors per unit of scale.
+///
+/// Four sensors sit diagonally away from a hidden distress beacon, each with a range that stops just
+/// short of it. Between them they cover the whole search area except the beacon, like the puzzle's
+/// own input. The rest are random sensors whose ranges also stop short of the beacon. The beacon is
+/// kept near the top of the area because part 2 scans every row above it.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let distress = (
+        rng.range(AREA / 4, AREA * 3 / 4),
+        rng.range(AREA / 16, AREA / 8),
+    );
+    let mut sensors = [(-1, -1), (-1, 1), (1, -1), (1, 1)]
+        .into_iter()
+        .map(|(dx, dy)| (distress.0 + dx * AREA, distress.1 + dy * AREA))
+        .collect::<Vec<_>>();
+    while sensors.len() < 34 * scale {
+        let sensor = (rng.range(0, AREA), rng.range(0, AREA));
+        if (sensor.0 - distress.0).abs() + (sensor.1 - distress.1).abs() >= 2 {
+            sensors.push(sensor);
+        }
+    }
+    rng.shuffle(&mut sensors);
+
+    let mut input = String::new();
+    for (x, y) in sensors {
+        let range = (x - distress.0).abs() + (y - distress.1).abs() - 1;
+        // Any point on the edge of the range will do for the beacon.
+        let beacon_dx = rng.range(-range, range + 1);
+        let beacon_dy = (range - beacon_dx.abs()) * *rng.choose(&[-1, 1]);
+        input.push_str(&format!(
+            "Sensor at x={x}, y={y}: closest beacon is at x={}, y={}\n",
+            x + beacon_dx,
+            y + beacon_dy
+        ));
+    }
+    input
+}
@@ -1,60 +1,29 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use std::{
-    cmp::Ordering,
-    num::ParseIntError,
-    ops::{Add, Sub},
-    str::FromStr,
+    collections::HashSet,
     time::{Duration, Instant},
 };
 
+use crate::{
+    days::Solution,
+    geometry::Point,
+    interval::{Interval, IntervalSet},
+    scanner::{ParseError, ScanError, Scanner},
+};
 use input::INPUT;
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl FromStr for Point {
-    type Err = ParseIntError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let x_index = s.match_indices("x=").next().unwrap().0;
-        let mut tokens = s[x_index..].split(',');
-        let x = tokens.next().unwrap()[2..].parse::<i32>()?;
-        let y = tokens.next().unwrap()[3..].parse::<i32>()?;
-        Ok(Self { x, y })
-    }
-}
-
-impl Add for Point {
-    type Output = Self;
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
-
-impl Sub for Point {
-    type Output = Self;
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
-    }
-}
-
-impl Point {
-    fn manhattan_distance(&self, rhs: &Point) -> usize {
-        ((self.x - rhs.x).abs() + (self.y - rhs.y).abs()) as usize
-    }
+fn scan_point(scanner: &mut Scanner) -> Result<Point, ScanError> {
+    scanner.expect_literal("x=")?;
+    let x = scanner.signed::<i32>()?;
+    scanner.expect_literal(", y=")?;
+    let y = scanner.signed::<i32>()?;
+    Ok(Point::new(x, y))
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -67,7 +36,7 @@ struct EffectiveSensor {
 }
 
 impl EffectiveSensor {
-    fn span_at(&self, y: i32) -> Option<Span> {
+    fn span_at(&self, y: i32) -> Option<Interval> {
         // When the sensor is on the same row as the query row, the x-range has a width equal to 2*radius+1
         // (the +1 is for the sensor itself).
         // When the sensor is 1 row away, the width is 2 less...
@@ -76,112 +45,75 @@ impl EffectiveSensor {
         if x_range < 0 {
             None
         } else {
-            Some(Span {
-                start: self.sensor.0.x - x_range,
-                length: x_range as usize * 2,
-            })
+            Some(Interval::inclusive(
+                self.sensor.0.x - x_range,
+                self.sensor.0.x + x_range,
+            ))
         }
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-struct Span {
-    start: i32,
-    length: usize,
-}
-
-impl PartialOrd for Span {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for Span {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.start.cmp(&other.start)
+/// Each sensor and the beacon closest to it.
+fn scan_reports(input: &str) -> Result<Vec<(Sensor, Point)>, ScanError> {
+    let mut reports = vec![];
+    let mut scanner = Scanner::new(input);
+    while !scanner.is_at_end() {
+        scanner.expect_literal("Sensor at ")?;
+        let sensor = Sensor(scan_point(&mut scanner)?);
+        scanner.expect_literal(": closest beacon is at ")?;
+        let beacon = scan_point(&mut scanner)?;
+        scanner.end_of_line()?;
+        reports.push((sensor, beacon));
     }
+    Ok(reports)
 }
 
-impl Span {
-    fn end(&self) -> i32 {
-        self.start + self.length as i32
-    }
-
-    fn merge(&self, rhs: &Self) -> Option<Self> {
-        let end = self.end();
-        if end >= rhs.start {
-            Some(Self {
-                start: self.start,
-                length: (end.max(rhs.end()) - self.start) as usize,
-            })
-        } else {
-            None
-        }
-    }
+/// Gather the x-ranges the sensors cover on row 'y' in 'spans'.
+fn cover_row(sensors: &[EffectiveSensor], y: i32, spans: &mut IntervalSet) {
+    spans.clear();
+    spans.extend(sensors.iter().filter_map(|sensor| sensor.span_at(y)));
 }
 
 fn solve_for(input: &str, part1_y: i32) -> Result<(usize, usize, Duration)> {
     let timer = Instant::now();
-    let effective_sensors = input
-        .lines()
-        .map(|line| {
-            let mut parts = line.split(':');
-            let sensor = parts.next().unwrap();
-            let beacon = parts.next().unwrap();
-            let sensor = sensor.parse::<Point>()?;
-            let sensor = Sensor(sensor);
-            let beacon = beacon.parse::<Point>()?;
-            Ok(EffectiveSensor {
-                sensor,
-                range: sensor.0.manhattan_distance(&beacon),
-            })
-        })
-        .collect::<Result<Vec<_>>>()?;
+    let mut effective_sensors = vec![];
+    let mut beacons = HashSet::new();
+    let reports = scan_reports(input).map_err(|error| ParseError::new(15, input, error))?;
+    for (sensor, beacon) in reports {
+        beacons.insert(beacon);
+        effective_sensors.push(EffectiveSensor {
+            sensor,
+            range: sensor.0.manhattan_distance(beacon) as usize,
+        });
+    }
     let parse_duration = timer.elapsed();
 
     // Beacons serve to define a sensor's effective range.
     // Given a row, which sensors intersect it?
     // Given the set of sensors intersecting a row, what is the x-range of the intersection?
 
-    let compute_spans = |y| {
-        let mut spans = effective_sensors
-            .iter()
-            .filter_map(|sensor| sensor.span_at(y))
-            .collect::<Vec<Span>>();
-        spans.sort_unstable();
-        let mut i = 0;
-        while i + 1 < spans.len() {
-            if let Some(merge) = spans[i].merge(&spans[i + 1]) {
-                spans[i] = merge;
-                spans.remove(i + 1);
-            } else {
-                i += 1;
-            }
-        }
-        spans
-    };
+    let mut spans = IntervalSet::new();
 
-    let part1 = compute_spans(part1_y)
-        .iter()
-        .map(|span| span.length)
-        .sum::<usize>();
+    // A beacon can't be anywhere a sensor covers, except where there already is one.
+    cover_row(&effective_sensors, part1_y, &mut spans);
+    let part1 =
+        spans.coverage() as usize - beacons.iter().filter(|beacon| beacon.y == part1_y).count();
 
-    let mut y = effective_sensors
+    // The distress beacon is in a square twice the size of part 1's row, in the example and the
+    // real input alike, at the only place no sensor covers.
+    let area = Interval::inclusive(0, part1_y * 2);
+    let top = effective_sensors
         .iter()
         .map(|sensor| sensor.sensor.0.y - sensor.range as i32)
         .min()
         .unwrap()
-        .max(0);
-    let x = loop {
-        let spans = compute_spans(y);
-        if spans.len() > 1 {
-            let x = spans[0].end() + 1;
-            if x >= 0 {
-                break x;
-            }
-        }
-        y += 1;
-    };
+        .max(area.start);
+    let (x, y) = (top..area.end)
+        .find_map(|y| {
+            cover_row(&effective_sensors, y, &mut spans);
+            spans.gaps(area).next().map(|gap| (gap.start, y))
+        })
+        .ok_or_else(|| anyhow!("The sensors cover the whole search area"))?;
 
     let part2 = x as usize * 4_000_000 + y as usize;
 
@@ -197,3 +129,8 @@ pub(crate) fn solve() -> (usize, usize, Duration) {
         }
     }
 }
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input, 2_000_000)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}
@@ -26,3 +26,13 @@ fn part2() {
     assert_eq!(56_000_011, solve_for(INPUT, 10).unwrap().1);
     assert_eq!(12051287042458, solve_for(input::INPUT, 2_000_000).unwrap().1);
 }
+
+#[test]
+fn parse_error() {
+    let input = INPUT.replacen("y=16:", "y=16;", 1);
+    let error = solve_for(&input, 10).unwrap_err().to_string();
+    assert!(
+        error.starts_with("day 15, line 2, column 20: expected \": closest beacon is at \""),
+        "{error}"
+    );
+}
@@ -1,3 +1,4 @@
+use crate::scanner::{ScanError, ScanErrorKind};
 use anyhow::{anyhow, Result};
 use std::str::FromStr;
 
@@ -12,26 +13,35 @@ const HEX_RADIX: u32 = 16;
 const BITS_PER_HEX_DIGIT: usize = 4;
 
 impl FromStr for BitStream {
-    type Err = anyhow::Error;
+    type Err = ScanError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let bytes = s
-            .lines()
-            .flat_map(|line| {
-                line.chars()
-                    .step_by(2)
-                    .zip(line.chars().skip(1).step_by(2))
-                    .map(|(a, b)| match a.to_digit(16) {
-                        Some(a) => {
-                            let b = b
-                                .to_digit(HEX_RADIX)
-                                .ok_or_else(|| anyhow!("Failed to convert to hex"))?;
-                            Ok(((a << BITS_PER_HEX_DIGIT) | b) as u8)
-                        }
-                        None => Err(anyhow!("Failed to convert to hex")),
-                    })
-            })
-            .collect::<Result<Vec<u8>>>()?;
+        let mut bytes = vec![];
+        for (index, line) in s.lines().enumerate() {
+            let digit = |column: usize, c: char| {
+                c.to_digit(HEX_RADIX).ok_or_else(|| ScanError {
+                    line: index + 1,
+                    column: column + 1,
+                    kind: ScanErrorKind::Unexpected(format!("{c:?}, expected a hex digit")),
+                })
+            };
+            let chars = line.chars().collect::<Vec<_>>();
+            // Each byte is two digits, so a lone last digit would be lost.
+            if chars.len() % 2 == 1 {
+                return Err(ScanError {
+                    line: index + 1,
+                    column: chars.len() + 1,
+                    kind: ScanErrorKind::Unexpected(
+                        "end of line after an odd number of hex digits".to_owned(),
+                    ),
+                });
+            }
+            for (pair, digits) in chars.chunks_exact(2).enumerate() {
+                let high = digit(pair * 2, digits[0])?;
+                let low = digit(pair * 2 + 1, digits[1])?;
+                bytes.push(((high << BITS_PER_HEX_DIGIT) | low) as u8);
+            }
+        }
         Ok(Self {
             bytes,
             bit_index: 0,
@@ -45,9 +55,16 @@ impl BitStream {
         self.bit_index
     }
 
-    /// Take the next 'bit_count' bits from the stream and return them interpreted as a T.
-    pub fn take(&mut self, bit_count: usize) -> u32 {
+    /// Take the next 'bit_count' bits from the stream and return them interpreted as a T. An error
+    /// if the stream ends first.
+    pub fn take(&mut self, bit_count: usize) -> Result<u32> {
         assert!(bit_count <= u32::BITS as usize);
+        let length = self.bytes.len() * BITS_PER_BYTE;
+        if self.bit_index + bit_count > length {
+            return Err(anyhow!(
+                "The transmission ends after {length} bits, partway through a packet"
+            ));
+        }
 
         let current_byte_index = self.bit_index / BITS_PER_BYTE;
         let current_bit_index = self.bit_index % BITS_PER_BYTE;
@@ -72,7 +89,7 @@ impl BitStream {
             bits = push_byte(next_byte_index, bits) >> (BITS_PER_BYTE - next_bit_index);
         }
 
-        bits
+        Ok(bits)
     }
 }
 
@@ -84,12 +101,13 @@ fn parse() {
     INPUT.chars().for_each(|c| {
         assert_eq!(
             c.to_digit(HEX_RADIX).unwrap(),
-            bit_stream.take(BITS_PER_HEX_DIGIT),
+            bit_stream.take(BITS_PER_HEX_DIGIT).unwrap(),
             "Failed to extract character: {}",
             c
         );
     });
 
     bit_stream.bit_index = 0; // Reset the BitStream before reading it again.
-    assert_eq!(0b110100101111111000101000, bit_stream.take(24));
+    assert_eq!(0b110100101111111000101000, bit_stream.take(24).unwrap());
+    assert!(bit_stream.take(1).is_err());
 }
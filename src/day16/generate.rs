@@ -0,0 +1,70 @@
+use crate::generate::Rng;
+
+// Sum, minimum, maximum, greater than, less than and equal to. Product is left out because random
+// products overflow.
+const OPERATORS: [u32; 6] = [0, 2, 3, 5, 6, 7];
+const COMPARISONS: [u32; 3] = [5, 6, 7];
+
+/// A transmission of about 100 packets per unit of scale.
+pub(crate) fn generate(rng: &mut Rng, scale: usize) -> String {
+    let mut bits = vec![];
+    packet(rng, 100 * scale, &mut bits);
+    while bits.len() % 8 != 0 {
+        bits.push(false);
+    }
+    bits.chunks(4)
+        .map(|nibble| {
+            let value = nibble
+                .iter()
+                .fold(0, |value, &bit| value << 1 | u32::from(bit));
+            char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect()
+}
+
+fn push_bits(bits: &mut Vec<bool>, value: u64, count: usize) {
+    bits.extend((0..count).rev().map(|bit| value >> bit & 1 == 1));
+}
+
+/// Append a packet containing about 'budget' packets in total.
+fn packet(rng: &mut Rng, budget: usize, bits: &mut Vec<bool>) {
+    push_bits(bits, rng.below(8) as u64, 3);
+    if budget <= 1 {
+        push_bits(bits, 4, 3);
+        let mut literal = rng.below(1 << 12);
+        let mut groups = vec![];
+        loop {
+            groups.push(literal & 0xf);
+            literal >>= 4;
+            if literal == 0 {
+                break;
+            }
+        }
+        for (index, group) in groups.iter().rev().enumerate() {
+            push_bits(bits, u64::from(index + 1 != groups.len()), 1);
+            push_bits(bits, *group as u64, 4);
+        }
+        return;
+    }
+
+    let operator = *rng.choose(&OPERATORS);
+    let child_count = if COMPARISONS.contains(&operator) {
+        2
+    } else {
+        (2 + rng.below(4)).min(budget)
+    };
+    let child_budget = (budget - 1) / child_count;
+    let mut children = vec![];
+    for _ in 0..child_count {
+        packet(rng, child_budget, &mut children);
+    }
+    push_bits(bits, operator as u64, 3);
+    if children.len() < 1 << 15 && rng.chance(1, 2) {
+        push_bits(bits, 0, 1);
+        push_bits(bits, children.len() as u64, 15);
+    } else {
+        push_bits(bits, 1, 1);
+        push_bits(bits, child_count as u64, 11);
+    }
+    bits.extend(children);
+}
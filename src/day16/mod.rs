@@ -1,8 +1,10 @@
 use self::{bit_stream::BitStream, input::INPUT};
+use crate::{days::Solution, scanner::ParseError};
 use anyhow::{anyhow, Result};
 use std::time::Duration;
 
-mod input;
+pub(crate) mod generate;
+pub(crate) mod input;
 
 #[cfg(test)]
 mod tests;
@@ -34,9 +36,19 @@ fn evaluate_packet(operator: u32, operands: &[Value]) -> Result<Value> {
             .copied()
             .max()
             .ok_or_else(|| anyhow!("No operands")),
-        GREATER_THAN => Ok(u64::from(operands[0] > operands[1])),
-        LESS_THAN => Ok(u64::from(operands[0] < operands[1])),
-        EQUAL_TO => Ok(u64::from(operands[0] == operands[1])),
+        GREATER_THAN | LESS_THAN | EQUAL_TO => {
+            let &[a, b] = operands else {
+                return Err(anyhow!(
+                    "Comparisons need 2 operands, not {}",
+                    operands.len()
+                ));
+            };
+            Ok(u64::from(match operator {
+                GREATER_THAN => a > b,
+                LESS_THAN => a < b,
+                _ => a == b,
+            }))
+        }
         bad_operator => Err(anyhow!("Bad operator: {}", bad_operator)),
     }
 }
@@ -51,16 +63,16 @@ const LENGTH_TYPE_PACKET_LENGTH: u32 = 0;
 const LENGTH_TYPE_SUB_PACKET_COUNT: u32 = 1;
 
 fn read_next_packet(bit_stream: &mut BitStream, version_sum: &mut u32) -> Result<Value> {
-    let version = bit_stream.take(VERSION_LENGTH);
+    let version = bit_stream.take(VERSION_LENGTH)?;
     *version_sum += version;
-    let type_id = bit_stream.take(TYPE_ID_LENGTH);
+    let type_id = bit_stream.take(TYPE_ID_LENGTH)?;
     match type_id {
         LITERAL => {
             let mut literal: Value = 0;
             let mut first_group = true;
             loop {
-                let last_group = bit_stream.take(1) == 0;
-                let group = bit_stream.take(LITERAL_GROUP_LENGTH);
+                let last_group = bit_stream.take(1)? == 0;
+                let group = bit_stream.take(LITERAL_GROUP_LENGTH)?;
                 if first_group {
                     first_group = false;
                 } else {
@@ -73,10 +85,10 @@ fn read_next_packet(bit_stream: &mut BitStream, version_sum: &mut u32) -> Result
             }
         }
         operator => {
-            let length_type_id = bit_stream.take(LENGTH_TYPE_ID_LENGTH);
+            let length_type_id = bit_stream.take(LENGTH_TYPE_ID_LENGTH)?;
             let operands = match length_type_id {
                 LENGTH_TYPE_PACKET_LENGTH => {
-                    let packet_size = bit_stream.take(PACKET_SIZE_LENGTH);
+                    let packet_size = bit_stream.take(PACKET_SIZE_LENGTH)?;
                     let mut operands = Vec::new();
                     let mut bits_consumed = 0;
                     while bits_consumed < packet_size {
@@ -94,7 +106,7 @@ fn read_next_packet(bit_stream: &mut BitStream, version_sum: &mut u32) -> Result
                     operands
                 }
                 LENGTH_TYPE_SUB_PACKET_COUNT => {
-                    let sub_packet_count = bit_stream.take(SUB_PACKET_COUNT_LENGTH);
+                    let sub_packet_count = bit_stream.take(SUB_PACKET_COUNT_LENGTH)?;
                     (0..sub_packet_count)
                         .map(|_| read_next_packet(bit_stream, version_sum))
                         .collect::<Result<Vec<Value>>>()?
@@ -107,7 +119,9 @@ fn read_next_packet(bit_stream: &mut BitStream, version_sum: &mut u32) -> Result
 }
 
 fn solve_for(input: &str) -> Result<(u32, Value, Duration)> {
-    let mut bit_stream = input.parse::<BitStream>()?;
+    let mut bit_stream = input
+        .parse::<BitStream>()
+        .map_err(|error| ParseError::new(16, input, error))?;
     let mut part1 = 0;
     let part2 = read_next_packet(&mut bit_stream, &mut part1)?;
     Ok((part1, part2, Duration::new(0, 0)))
@@ -122,3 +136,8 @@ pub(crate) fn solve() -> (u32, Value, Duration) {
         }
     }
 }
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}
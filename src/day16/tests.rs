@@ -22,3 +22,24 @@ fn part2() {
     assert_eq!(1, solve_for("9C0141080250320F1802104A08").unwrap().1);
     assert_eq!(1673210814091, solve_for(input::INPUT).unwrap().1);
 }
+
+#[test]
+fn bad_transmissions() {
+    let error = solve_for("D2FE2").unwrap_err().to_string();
+    assert!(
+        error.starts_with(
+            "day 16, line 1, column 6: unexpected end of line after an odd number of hex digits"
+        ),
+        "{error}"
+    );
+    // A literal cut off before its last group.
+    assert_eq!(
+        "The transmission ends after 16 bits, partway through a packet",
+        solve_for("D2FE").unwrap_err().to_string()
+    );
+    // A less-than packet with a single literal operand.
+    assert_eq!(
+        "Comparisons need 2 operands, not 1",
+        solve_for("1A004408").unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,122 @@
+use crate::generate::Rng;
+use anyhow::{anyhow, Result};
+use std::{
+    fmt::Display,
+    panic::{catch_unwind, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+/// Both answers for a day, rendered as text so days with different answer types can be handled
+/// uniformly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Solution {
+    pub(crate) part1: String,
+    pub(crate) part2: String,
+    pub(crate) parse_duration: Duration,
+}
+
+impl Solution {
+    pub(crate) fn new(part1: impl Display, part2: impl Display, parse_duration: Duration) -> Self {
+        Self {
+            part1: part1.to_string(),
+            part2: part2.to_string(),
+            parse_duration,
+        }
+    }
+}
+
+/// A day's solver and input generator, for commands that work on arbitrary inputs.
+pub(crate) struct Day {
+    pub(crate) number: u8,
+    /// Solve an arbitrary input, e.g. one from a generator.
+    pub(crate) solve_input: fn(&str) -> Result<Solution>,
+    /// The puzzle input built into the program.
+    pub(crate) input: &'static str,
+    /// Produce a valid input roughly 'scale' times the size of the puzzle input.
+    pub(crate) generate: fn(&mut Rng, usize) -> String,
+}
+
+/// Why an arbitrary input couldn't be solved.
+#[derive(Debug)]
+pub(crate) enum SolveError {
+    Failed(anyhow::Error),
+    Panicked,
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Failed(error) => write!(f, "{error:#}"),
+            Self::Panicked => write!(f, "The solver panicked on this input"),
+        }
+    }
+}
+
+impl Day {
+    /// Solve an input from outside the program, returning the solution and the total time taken.
+    /// Most solvers assume a well-formed input and panic on anything else, so panics are caught.
+    /// The release profile aborts on panic though, so this needs a debug or `release-unwind` build.
+    pub(crate) fn solve_untrusted(&self, input: &str) -> Result<(Solution, Duration), SolveError> {
+        let timer = Instant::now();
+        match catch_unwind(AssertUnwindSafe(|| (self.solve_input)(input))) {
+            Ok(Ok(solution)) => Ok((solution, timer.elapsed())),
+            Ok(Err(error)) => Err(SolveError::Failed(error)),
+            Err(_) => Err(SolveError::Panicked),
+        }
+    }
+}
+
+macro_rules! day {
+    ($number:literal, $day:ident) => {
+        Day {
+            number: $number,
+            solve_input: crate::$day::solve_input,
+            input: crate::$day::input::INPUT,
+            generate: crate::$day::generate::generate,
+        }
+    };
+}
+
+/// Every day compiled into this build.
+pub(crate) const DAYS: &[Day] = &[
+    #[cfg(feature = "day01")]
+    day!(1, day01),
+    #[cfg(feature = "day02")]
+    day!(2, day02),
+    #[cfg(feature = "day03")]
+    day!(3, day03),
+    #[cfg(feature = "day04")]
+    day!(4, day04),
+    #[cfg(feature = "day05")]
+    day!(5, day05),
+    #[cfg(feature = "day06")]
+    day!(6, day06),
+    #[cfg(feature = "day07")]
+    day!(7, day07),
+    #[cfg(feature = "day08")]
+    day!(8, day08),
+    #[cfg(feature = "day09")]
+    day!(9, day09),
+    #[cfg(feature = "day10")]
+    day!(10, day10),
+    #[cfg(feature = "day11")]
+    day!(11, day11),
+    #[cfg(feature = "day12")]
+    day!(12, day12),
+    #[cfg(feature = "day13")]
+    day!(13, day13),
+    #[cfg(feature = "day14")]
+    day!(14, day14),
+    #[cfg(feature = "day15")]
+    day!(15, day15),
+    #[cfg(feature = "day16")]
+    day!(16, day16),
+];
+
+/// Accept "7", "07" or "day07".
+pub(crate) fn parse_day_number(s: &str) -> Result<u8> {
+    s.strip_prefix("day")
+        .unwrap_or(s)
+        .parse::<u8>()
+        .map_err(|_| anyhow!("Not a day: {s}"))
+}
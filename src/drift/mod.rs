@@ -0,0 +1,158 @@
+use crate::{
+    artifact::{
+        self, file_name, fingerprint, load_runs, parse_file_name, RunRecord, RUN_DESCRIPTION,
+    },
+    cli::Args,
+    days::{parse_day_number, DAYS},
+};
+use anyhow::{anyhow, Context, Result};
+use chrono::NaiveDateTime;
+use std::{collections::HashSet, fs, path::Path};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const USAGE: &str = "drift [DAY...]";
+
+/// Lists runs whose answers were wrong, so they aren't reported as drift. One artifact path, such
+/// as `day01/run_2022-11-30_21-58-37.csv`, per line, and `#` starts a comment. Runs keep these
+/// names after `compact` has merged them into a history file.
+pub(crate) const SUPERSEDED_FILE: &str = "superseded.txt";
+
+pub(crate) type Superseded = HashSet<(u8, NaiveDateTime)>;
+
+pub(crate) fn parse_superseded(contents: &str) -> Result<Superseded> {
+    contents
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            (!line.is_empty()).then_some((index + 1, line))
+        })
+        .map(|(line_number, line)| {
+            line.strip_prefix("day")
+                .and_then(|line| line.split_once('/'))
+                .and_then(|(day, file)| match parse_file_name(file)? {
+                    (RUN_DESCRIPTION, timestamp) => Some((day.parse().ok()?, timestamp)),
+                    _ => None,
+                })
+                .ok_or_else(|| anyhow!("Line {line_number}: not a run artifact: {line}"))
+        })
+        .collect()
+}
+
+fn load_superseded(folder: &Path) -> Result<Superseded> {
+    let path = folder.join(SUPERSEDED_FILE);
+    match fs::read_to_string(&path) {
+        Ok(contents) => {
+            parse_superseded(&contents).with_context(|| format!("Reading {}", path.display()))
+        }
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Superseded::new()),
+        Err(error) => Err(error).with_context(|| format!("Reading {}", path.display())),
+    }
+}
+
+/// The latest run, and the earlier runs on the same input that got different answers.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Drift<'a> {
+    pub(crate) latest: &'a RunRecord,
+    pub(crate) earlier: Vec<&'a RunRecord>,
+}
+
+/// Look for drift in a day's 'runs', oldest first. Runs recorded before inputs were fingerprinted
+/// are taken to be on the built-in input, whose fingerprint is 'builtin', since all runs are.
+pub(crate) fn find_drift<'a>(
+    runs: &'a [RunRecord],
+    superseded: &Superseded,
+    builtin: Option<&str>,
+) -> Option<Drift<'a>> {
+    let input = |run: &RunRecord| run.input.clone().or_else(|| builtin.map(str::to_owned));
+    let mut runs = runs
+        .iter()
+        .filter(|run| !superseded.contains(&(run.day, run.timestamp)));
+    let latest = runs.next_back()?;
+    let earlier = runs
+        .filter(|run| input(run) == input(latest))
+        .filter(|run| (&run.part1, &run.part2) != (&latest.part1, &latest.part2))
+        .collect::<Vec<_>>();
+    (!earlier.is_empty()).then_some(Drift { latest, earlier })
+}
+
+fn artifact_name(run: &RunRecord) -> String {
+    format!(
+        "day{:02}/{}",
+        run.day,
+        file_name(RUN_DESCRIPTION, run.timestamp)
+    )
+}
+
+fn describe(drift: &Drift) -> String {
+    // Keep multi-line answers, such as day 10's, inside the warning.
+    let answers = |run: &RunRecord| {
+        format!("part1 = {}, part2 = {}", run.part1, run.part2)
+            .trim_end()
+            .replace('\n', "\n!!!           ")
+    };
+    let mut description = format!(
+        "!!! ANSWER DRIFT on day {}: the answers changed for the same input.\n!!!   latest  {}: {}\n",
+        drift.latest.day,
+        artifact_name(drift.latest),
+        answers(drift.latest)
+    );
+    for run in &drift.earlier {
+        description += &format!("!!!   earlier {}: {}\n", artifact_name(run), answers(run));
+    }
+    description += &format!(
+        "!!! If the earlier answers were wrong, list those runs in {}.",
+        artifact::folder().join(SUPERSEDED_FILE).display()
+    );
+    description
+}
+
+/// Check one day's history, returning a description of any drift.
+fn check_day(folder: &Path, day: u8, superseded: &Superseded) -> Result<Option<String>> {
+    let runs = load_runs(folder, day)?;
+    let builtin = DAYS
+        .iter()
+        .find(|found| found.number == day)
+        .map(|found| fingerprint(found.input));
+    Ok(find_drift(&runs, superseded, builtin.as_deref()).map(|drift| describe(&drift)))
+}
+
+/// Warn about drift after a day has been solved and its artifact written.
+pub(crate) fn warn(day: u8) {
+    let folder = artifact::folder();
+    match load_superseded(folder).and_then(|superseded| check_day(folder, day, &superseded)) {
+        Ok(Some(description)) => eprintln!("{description}\n"),
+        Ok(None) => {}
+        Err(error) => eprintln!("warning: couldn't check day {day} for answer drift: {error:#}"),
+    }
+}
+
+pub(crate) fn run(args: &[String]) -> Result<()> {
+    let args = Args::parse(args, &[], &[])?;
+    let days = if args.positional().is_empty() {
+        (1..=25).collect()
+    } else {
+        args.positional()
+            .iter()
+            .map(|day| parse_day_number(day))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let folder = artifact::folder();
+    let superseded = load_superseded(folder)?;
+    let mut drifted = vec![];
+    for day in days {
+        if let Some(description) = check_day(folder, day, &superseded)? {
+            println!("{description}\n");
+            drifted.push(format!("day{day:02}"));
+        }
+    }
+    if drifted.is_empty() {
+        println!("No answer drift.");
+        Ok(())
+    } else {
+        Err(anyhow!("Answer drift: {}", drifted.join(", ")))
+    }
+}
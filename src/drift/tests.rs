@@ -0,0 +1,59 @@
+use super::{find_drift, parse_superseded, Superseded};
+use crate::artifact::RunRecord;
+use chrono::NaiveDate;
+
+fn run(hour: u32, part2: &str, input: Option<&str>) -> RunRecord {
+    RunRecord {
+        day: 1,
+        timestamp: NaiveDate::from_ymd_opt(2022, 11, 30)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap(),
+        build_config: "Release".to_owned(),
+        part1: "72070".to_owned(),
+        part2: part2.to_owned(),
+        duration: 0.1,
+        parse_duration: 0.1,
+        input: input.map(str::to_owned),
+        environment: None,
+    }
+}
+
+#[test]
+fn superseded() {
+    let superseded =
+        parse_superseded("# Wrong answers\n\nday01/run_2022-11-30_21-00-00.csv  # before part 2\n")
+            .unwrap();
+    assert_eq!(1, superseded.len());
+    assert!(superseded.contains(&(1, run(21, "", None).timestamp)));
+
+    assert!(parse_superseded("day01/run_yesterday.csv").is_err());
+    assert!(parse_superseded("notes.txt").is_err());
+}
+
+#[test]
+fn drift() {
+    let runs = [
+        run(20, "0", None),
+        run(21, "211805", None),
+        run(22, "5", Some("other")),
+        run(23, "211805", Some("builtin")),
+    ];
+    let none = Superseded::new();
+
+    // Unfingerprinted runs are on the built-in input, and runs on other inputs are ignored.
+    let drift = find_drift(&runs, &none, Some("builtin")).unwrap();
+    assert_eq!(&runs[3], drift.latest);
+    assert_eq!(vec![&runs[0]], drift.earlier);
+
+    assert_eq!(None, find_drift(&runs[1..], &none, Some("builtin")));
+    let superseded = [(1, runs[0].timestamp)].into_iter().collect();
+    assert_eq!(None, find_drift(&runs, &superseded, Some("builtin")));
+
+    // Without the built-in fingerprint, older runs can't be compared with newer ones.
+    assert_eq!(None, find_drift(&runs, &none, None));
+    assert_eq!(
+        vec![&runs[0]],
+        find_drift(&runs[..2], &none, None).unwrap().earlier
+    );
+}
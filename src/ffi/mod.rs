@@ -0,0 +1,179 @@
+//! A C ABI over the solvers, built into the `cdylib`. The header is `ffi/aoc_2022.h`, generated by
+//! [header], and `ffi/test.c` shows how to call it. Build it with `--profile release-unwind`, as
+//! `ffi/Makefile` does: the release profile aborts on panic, so it never returns
+//! `AOC_ERROR_PANICKED`.
+
+use crate::{
+    days::{Day, SolveError, DAYS},
+    json,
+};
+use std::{
+    ffi::{c_char, c_int, CString},
+    ptr, slice,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// What `aoc_solve` returns. The values are part of the ABI, so only ever add new ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Status {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidUtf8 = 2,
+    UnknownDay = 3,
+    BadInput = 4,
+    Panicked = 5,
+}
+
+impl Status {
+    #[cfg(test)]
+    const ALL: [(Self, &'static str, &'static str); 6] = [
+        (Self::Ok, "AOC_OK", "Solved."),
+        (
+            Self::NullPointer,
+            "AOC_ERROR_NULL_POINTER",
+            "A required pointer was null.",
+        ),
+        (
+            Self::InvalidUtf8,
+            "AOC_ERROR_INVALID_UTF8",
+            "The input isn't UTF-8.",
+        ),
+        (
+            Self::UnknownDay,
+            "AOC_ERROR_UNKNOWN_DAY",
+            "The day isn't enabled in this build of the library.",
+        ),
+        (
+            Self::BadInput,
+            "AOC_ERROR_BAD_INPUT",
+            "The solver rejected the input.",
+        ),
+        (Self::Panicked, "AOC_ERROR_PANICKED", "The solver panicked."),
+    ];
+}
+
+/// Day 0, whose solver always panics, for `ffi/test.c` to check that a panic comes back as
+/// `AOC_ERROR_PANICKED` without relying on a bug in a real day. Only the `ffi-test` feature
+/// exposes it.
+const PANIC_DAYS: &[Day] = &[Day {
+    number: 0,
+    solve_input: |_| panic!("Day 0 always panics"),
+    input: "",
+    generate: |_, _| String::new(),
+}];
+
+/// Solve 'input' as 'day' from 'days', returning the status and the JSON to hand back.
+pub(crate) fn solve_json(day: u8, input: &[u8], days: &[Day]) -> (Status, String) {
+    let Ok(input) = std::str::from_utf8(input) else {
+        return (Status::InvalidUtf8, json::error("The input isn't UTF-8"));
+    };
+    let Some(found) = days.iter().find(|found| found.number == day) else {
+        return (
+            Status::UnknownDay,
+            json::error(&format!("Day {day} isn't enabled in this build")),
+        );
+    };
+    match found.solve_untrusted(input) {
+        Ok((solution, elapsed)) => (Status::Ok, json::solution(day, &solution, elapsed)),
+        Err(error) => {
+            let status = match error {
+                SolveError::Failed(_) => Status::BadInput,
+                SolveError::Panicked => Status::Panicked,
+            };
+            (status, json::solve_error(day, &error))
+        }
+    }
+}
+
+/// Solve 'len' bytes of 'input' as 'day'. Unless 'out_json' is null, it's set to a JSON object with
+/// either the answers and timings or an error message, which must be freed with `aoc_free_json`.
+///
+/// # Safety
+///
+/// 'input' must point to 'len' readable bytes, or may be null if 'len' is zero. 'out_json' must be
+/// null or point to a writable `char *`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    input: *const u8,
+    len: usize,
+    out_json: *mut *mut c_char,
+) -> c_int {
+    let (status, json) = if input.is_null() && len != 0 {
+        (Status::NullPointer, json::error("The input is null"))
+    } else {
+        let input = if len == 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(input, len)
+        };
+        let days = if cfg!(feature = "ffi-test") && day == 0 {
+            PANIC_DAYS
+        } else {
+            DAYS
+        };
+        solve_json(day, input, days)
+    };
+    if !out_json.is_null() {
+        // The JSON escapes control characters, so it can't contain a NUL.
+        *out_json = CString::new(json).map_or(ptr::null_mut(), CString::into_raw);
+    }
+    status as c_int
+}
+
+/// Free a string from `aoc_solve`. Null is ignored.
+///
+/// # Safety
+///
+/// 'json' must be null or have come from `aoc_solve`, and not have been freed already.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_json(json: *mut c_char) {
+    if !json.is_null() {
+        drop(CString::from_raw(json));
+    }
+}
+
+/// The C header for the functions above. Only the tests need it, to check and write the header.
+#[cfg(test)]
+pub(crate) fn header() -> String {
+    let statuses = Status::ALL
+        .iter()
+        .map(|(status, name, doc)| format!("    /* {doc} */\n    {name} = {},\n", *status as c_int))
+        .collect::<String>();
+    format!(
+        "/* Generated from src/ffi/mod.rs by `cargo test write_ffi_header -- --ignored`. */
+
+#ifndef AOC_2022_H
+#define AOC_2022_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+/* Returned by aoc_solve. */
+enum aoc_status {{
+{statuses}}};
+
+/*
+ * Solve `len` bytes of `input` as `day`. Unless `out_json` is null, it's set to a JSON object
+ * with either the answers and timings or an error message, which must be freed with
+ * aoc_free_json. `input` may be null if `len` is zero. Returns an aoc_status.
+ */
+int aoc_solve(uint8_t day, const uint8_t *input, size_t len, char **out_json);
+
+/* Free a string from aoc_solve. Null is ignored. */
+void aoc_free_json(char *json);
+
+#ifdef __cplusplus
+}}
+#endif
+
+#endif
+"
+    )
+}
@@ -0,0 +1,79 @@
+use super::{aoc_free_json, aoc_solve, header, solve_json, Status};
+use crate::days::{Day, Solution};
+use anyhow::anyhow;
+use std::{
+    ffi::{c_char, CStr},
+    fs, ptr,
+    time::Duration,
+};
+
+const HEADER_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/ffi/aoc_2022.h");
+
+const TEST_DAYS: &[Day] = &[Day {
+    number: 2,
+    solve_input: |input| match input {
+        "" => Err(anyhow!("Empty input")),
+        "panic" => panic!("Test panic"),
+        _ => Ok(Solution::new(input.len(), "x", Duration::ZERO)),
+    },
+    input: "",
+    generate: |_, _| String::new(),
+}];
+
+#[test]
+fn solve() {
+    let (status, json) = solve_json(2, b"abc", TEST_DAYS);
+    assert_eq!(Status::Ok, status);
+    assert!(json.starts_with(r#"{"day":2,"part1":"3","part2":"x","parse_ms":0,"#));
+
+    assert_eq!(
+        (
+            Status::BadInput,
+            r#"{"day":2,"error":"Empty input"}"#.to_owned()
+        ),
+        solve_json(2, b"", TEST_DAYS)
+    );
+    assert_eq!(Status::Panicked, solve_json(2, b"panic", TEST_DAYS).0);
+    assert_eq!(Status::UnknownDay, solve_json(3, b"abc", TEST_DAYS).0);
+    assert_eq!(Status::InvalidUtf8, solve_json(2, b"\xff", TEST_DAYS).0);
+}
+
+#[test]
+fn c_abi() {
+    let mut json: *mut c_char = ptr::null_mut();
+    unsafe {
+        assert_eq!(
+            Status::NullPointer as i32,
+            aoc_solve(1, ptr::null(), 1, &mut json)
+        );
+        assert_eq!(
+            r#"{"error":"The input is null"}"#,
+            CStr::from_ptr(json).to_str().unwrap()
+        );
+        aoc_free_json(json);
+
+        // No day has number 26, and a null input is fine when it's empty.
+        assert_eq!(
+            Status::UnknownDay as i32,
+            aoc_solve(26, ptr::null(), 0, ptr::null_mut())
+        );
+        aoc_free_json(ptr::null_mut());
+    }
+}
+
+#[test]
+fn header_is_current() {
+    let committed = fs::read_to_string(HEADER_PATH)
+        .unwrap()
+        .replace("\r\n", "\n");
+    assert!(
+        committed == header(),
+        "{HEADER_PATH} is out of date, regenerate it with `cargo test write_ffi_header -- --ignored`"
+    );
+}
+
+#[test]
+#[ignore]
+fn write_ffi_header() {
+    fs::write(HEADER_PATH, header()).unwrap();
+}
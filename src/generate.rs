@@ -0,0 +1,47 @@
+/// A small, seedable xorshift64* generator so generated inputs are reproducible without pulling in
+/// a dependency.
+#[derive(Clone, Debug)]
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        // Zero is a fixed point of xorshift.
+        Self(seed.max(1))
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Uniform in [low, high). Panics if the range is empty.
+    pub(crate) fn range(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low < high, "empty range {low}..{high}");
+        low + (self.next_u64() % (high - low) as u64) as i64
+    }
+
+    /// Uniform in [0, count). Panics if count is zero.
+    #[cfg_attr(not(all_days), allow(dead_code))]
+    pub(crate) fn below(&mut self, count: usize) -> usize {
+        self.range(0, count as i64) as usize
+    }
+
+    #[cfg_attr(not(all_days), allow(dead_code))]
+    pub(crate) fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    #[cfg_attr(not(all_days), allow(dead_code))]
+    pub(crate) fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    #[cfg_attr(not(all_days), allow(dead_code))]
+    pub(crate) fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
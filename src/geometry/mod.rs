@@ -0,0 +1,368 @@
+//! Points and vectors on the integer plane, with `y` growing downwards as puzzle maps are drawn.
+
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// An integer type that points and vectors can be made of.
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) trait Coordinate:
+    Copy
+    + Debug
+    + Default
+    + Hash
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The distance between two values, which is never negative.
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+/// A coordinate that can go negative, which directions need.
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) trait Signed: Coordinate + Neg<Output = Self> {}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {$(
+        impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        }
+    )*};
+}
+
+impl_coordinate!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl Signed for $t {}
+    )*};
+}
+
+impl_signed!(i8, i16, i32, i64, isize);
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Point<T = i32> {
+    pub(crate) x: T,
+    pub(crate) y: T,
+}
+
+/// The difference between two points.
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub(crate) struct Vector<T = i32> {
+    pub(crate) x: T,
+    pub(crate) y: T,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl<T: Coordinate> Point<T> {
+    pub(crate) const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub(crate) fn manhattan_distance(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// The number of king's moves between the points, where diagonal steps count as one.
+    #[cfg(test)]
+    pub(crate) fn chebyshev_distance(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+}
+
+#[cfg(test)]
+impl<T: Signed> Point<T> {
+    /// The four points sharing an edge with this one, in [Direction::ALL] order.
+    pub(crate) fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    /// The eight points around this one, in [Direction8::ALL] order.
+    pub(crate) fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl<T: Coordinate> Vector<T> {
+    pub(crate) const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub(crate) fn manhattan_length(self) -> T {
+        self.x.distance(T::ZERO) + self.y.distance(T::ZERO)
+    }
+
+    pub(crate) fn chebyshev_length(self) -> T {
+        self.x.distance(T::ZERO).max(self.y.distance(T::ZERO))
+    }
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl<T: Signed> Vector<T> {
+    /// Each component clamped to -1, 0 or 1: the king's move towards where this vector points.
+    pub(crate) fn signum(self) -> Self {
+        let signum = |value: T| value.clamp(-T::ONE, T::ONE);
+        Self::new(signum(self.x), signum(self.y))
+    }
+
+    /// A quarter turn clockwise, as seen on screen.
+    #[cfg(test)]
+    pub(crate) fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// A quarter turn anticlockwise, as seen on screen.
+    #[cfg(test)]
+    pub(crate) fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+// Anything that converts to a vector, such as a direction, can be added to a point.
+impl<T: Coordinate, V: Into<Vector<T>>> Add<V> for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: V) -> Self::Output {
+        let rhs = rhs.into();
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coordinate, V: Into<Vector<T>>> AddAssign<V> for Point<T> {
+    fn add_assign(&mut self, rhs: V) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> Sub<Vector<T>> for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector<T>) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> Add for Vector<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Vector<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Vector<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Signed> Neg for Vector<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// The 4-neighbourhood: a step along one axis.
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub(crate) enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl Direction {
+    /// Clockwise from up.
+    pub(crate) const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    #[cfg(test)]
+    pub(crate) fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    #[cfg(test)]
+    pub(crate) fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    #[cfg(test)]
+    pub(crate) fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The direction a vector along one axis points in. Diagonal and zero vectors have none.
+    pub(crate) fn from_vector<T: Signed>(vector: Vector<T>) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| Vector::from(*direction) == vector.signum())
+    }
+}
+
+impl<T: Signed> From<Direction> for Vector<T> {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self::new(T::ZERO, -T::ONE),
+            Direction::Right => Self::new(T::ONE, T::ZERO),
+            Direction::Down => Self::new(T::ZERO, T::ONE),
+            Direction::Left => Self::new(-T::ONE, T::ZERO),
+        }
+    }
+}
+
+/// The 8-neighbourhood: a king's move.
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub(crate) enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl Direction8 {
+    /// Clockwise from up.
+    pub(crate) const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// An eighth of a turn clockwise.
+    #[cfg(test)]
+    pub(crate) fn rotate_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// An eighth of a turn anticlockwise.
+    #[cfg(test)]
+    pub(crate) fn rotate_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+impl<T: Signed> From<Direction8> for Vector<T> {
+    fn from(direction: Direction8) -> Self {
+        let (x, y) = match direction {
+            Direction8::Up => (T::ZERO, -T::ONE),
+            Direction8::UpRight => (T::ONE, -T::ONE),
+            Direction8::Right => (T::ONE, T::ZERO),
+            Direction8::DownRight => (T::ONE, T::ONE),
+            Direction8::Down => (T::ZERO, T::ONE),
+            Direction8::DownLeft => (-T::ONE, T::ONE),
+            Direction8::Left => (-T::ONE, T::ZERO),
+            Direction8::UpLeft => (-T::ONE, -T::ONE),
+        };
+        Self::new(x, y)
+    }
+}
+
+/// The smallest rectangle holding some points, including its edges.
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub(crate) struct Bounds<T = i32> {
+    pub(crate) min: Point<T>,
+    pub(crate) max: Point<T>,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl<T: Coordinate> Bounds<T> {
+    /// The bounds of a single point.
+    pub(crate) fn new(point: Point<T>) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The bounds of 'points', or `None` if there aren't any.
+    #[cfg(test)]
+    pub(crate) fn from_points(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        points.for_each(|point| bounds.include(point));
+        Some(bounds)
+    }
+
+    /// Grow to hold 'point'.
+    pub(crate) fn include(&mut self, point: Point<T>) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    #[cfg(test)]
+    pub(crate) fn contains(&self, point: Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub(crate) fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub(crate) fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+}
@@ -0,0 +1,87 @@
+use super::{Bounds, Direction, Direction8, Point, Vector};
+
+#[test]
+fn arithmetic() {
+    let point = Point::new(3, -2);
+    assert_eq!(Point::new(3, -3), point + Direction::Up);
+    assert_eq!(Point::new(2, -1), point + Direction8::DownLeft);
+    assert_eq!(Point::new(5, 2), point + Vector::new(2, 4));
+    assert_eq!(Point::new(1, -6), point - Vector::new(2, 4));
+    assert_eq!(Vector::new(3, -2), point - Point::default());
+    assert_eq!(Vector::new(6, -4), (point - Point::default()) * 2);
+    assert_eq!(Vector::new(-3, 2), -(point - Point::default()));
+
+    let mut point = point;
+    point += Direction::Right;
+    point += Vector::new(0, 5);
+    assert_eq!(Point::new(4, 3), point);
+
+    // Unsigned points still work with vectors.
+    let unsigned = Point::<usize>::new(500, 0) + Vector::new(1, 2);
+    assert_eq!(Point::new(501, 2), unsigned);
+}
+
+#[test]
+fn distances() {
+    let a = Point::new(8, 7);
+    let b = Point::new(2, 10);
+    assert_eq!(9, a.manhattan_distance(b));
+    assert_eq!(9, b.manhattan_distance(a));
+    assert_eq!(6, a.chebyshev_distance(b));
+    assert_eq!(9, (a - b).manhattan_length());
+    assert_eq!(6, (b - a).chebyshev_length());
+    assert_eq!(
+        4,
+        Point::<u8>::new(1, 5).manhattan_distance(Point::new(3, 3))
+    );
+    assert_eq!(Vector::new(1, -1), Vector::new(6, -3).signum());
+    assert_eq!(Vector::new(0, 1), Vector::new(0, 2).signum());
+}
+
+#[test]
+fn directions() {
+    assert_eq!(Direction::Right, Direction::Up.turn_right());
+    assert_eq!(Direction::Left, Direction::Up.turn_left());
+    assert_eq!(Direction::Up, Direction::Down.opposite());
+    for direction in Direction::ALL {
+        assert_eq!(direction, direction.turn_left().turn_right());
+        let vector = Vector::<i32>::from(direction);
+        assert_eq!(Vector::from(direction.turn_right()), vector.rotate_right());
+        assert_eq!(Vector::from(direction.turn_left()), vector.rotate_left());
+        assert_eq!(Some(direction), Direction::from_vector(vector * 7));
+        assert_eq!(
+            Vector::<i32>::from(direction),
+            Vector::from(Direction8::from(direction))
+        );
+    }
+    assert_eq!(None, Direction::from_vector(Vector::new(1, 1)));
+    assert_eq!(None, Direction::from_vector(Vector::new(0, 0)));
+
+    assert_eq!(Direction8::UpRight, Direction8::Up.rotate_right());
+    assert_eq!(Direction8::UpLeft, Direction8::Up.rotate_left());
+    let origin = Point::new(0, 0);
+    assert_eq!(4, origin.neighbours4().count());
+    assert!(origin
+        .neighbours4()
+        .all(|point| point.manhattan_distance(origin) == 1));
+    assert_eq!(8, origin.neighbours8().count());
+    assert!(origin
+        .neighbours8()
+        .all(|point| point.chebyshev_distance(origin) == 1));
+}
+
+#[test]
+fn bounds() {
+    assert_eq!(None, Bounds::<i32>::from_points([]));
+    let bounds =
+        Bounds::from_points([Point::new(3, 1), Point::new(-2, 4), Point::new(0, 0)]).unwrap();
+    assert_eq!(Point::new(-2, 0), bounds.min);
+    assert_eq!(Point::new(3, 4), bounds.max);
+    assert_eq!((6, 5), (bounds.width(), bounds.height()));
+    assert!(bounds.contains(Point::new(-2, 4)));
+    assert!(!bounds.contains(Point::new(4, 4)));
+
+    let mut bounds = Bounds::new(Point::<usize>::new(500, 0));
+    bounds.include(Point::new(498, 6));
+    assert_eq!((3, 7), (bounds.width(), bounds.height()));
+}
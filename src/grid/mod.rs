@@ -0,0 +1,265 @@
+#[cfg(test)]
+use crate::geometry::Direction8;
+use crate::{
+    geometry::{Direction, Point, Vector},
+    scanner::{ScanError, ScanErrorKind},
+};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A rectangle of cells stored row by row, addressed by points with (0, 0) at the top left.
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl<T: Clone> Grid<T> {
+    pub(crate) fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl<T> Grid<T> {
+    /// A grid whose cells are 'cell' of their points.
+    pub(crate) fn from_fn(
+        width: usize,
+        height: usize,
+        cell: impl FnMut(Point<usize>) -> T,
+    ) -> Self {
+        let cells = (0..width * height)
+            .map(|index| Point::new(index % width, index / width))
+            .map(cell)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a map drawn with a character per cell, turning each into a cell with 'cell'. Every
+    /// line must be as long as the first, and 'cell' returns `None` for characters that don't
+    /// belong.
+    pub(crate) fn parse(
+        text: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ScanError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (index, line) in text.lines().enumerate() {
+            let error = |column, kind| ScanError {
+                line: index + 1,
+                column,
+                kind,
+            };
+            let mut length = 0;
+            for (column, c) in line.chars().enumerate() {
+                if width == Some(column) {
+                    return Err(error(column + 1, ScanErrorKind::ExpectedEndOfLine));
+                }
+                cells.push(cell(c).ok_or_else(|| {
+                    error(column + 1, ScanErrorKind::Unexpected(format!("{c:?}")))
+                })?);
+                length += 1;
+            }
+            match width {
+                Some(width) if length < width => {
+                    return Err(error(
+                        length + 1,
+                        ScanErrorKind::Unexpected("end of line".to_owned()),
+                    ))
+                }
+                _ => width = Some(length),
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ScanError {
+                line: 1,
+                column: 1,
+                kind: ScanErrorKind::UnexpectedEndOfInput,
+            }),
+        }
+    }
+
+    pub(crate) fn width(&self) -> usize {
+        self.width
+    }
+
+    pub(crate) fn height(&self) -> usize {
+        self.height
+    }
+
+    pub(crate) fn contains(&self, point: Point<usize>) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub(crate) fn get(&self, point: Point<usize>) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub(crate) fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// The point one step from 'point', if that's still on the grid.
+    pub(crate) fn step(
+        &self,
+        point: Point<usize>,
+        direction: impl Into<Vector<isize>>,
+    ) -> Option<Point<usize>> {
+        let vector = direction.into();
+        let next = Point::new(
+            point.x.checked_add_signed(vector.x)?,
+            point.y.checked_add_signed(vector.y)?,
+        );
+        self.contains(next).then_some(next)
+    }
+
+    /// The neighbours sharing an edge with 'point', in [Direction::ALL] order.
+    pub(crate) fn neighbours4(
+        &self,
+        point: Point<usize>,
+    ) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The neighbours all around 'point', in [Direction8::ALL] order.
+    #[cfg(test)]
+    pub(crate) fn neighbours8(
+        &self,
+        point: Point<usize>,
+    ) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The cells from 'start' to the edge in 'direction', not counting 'start' itself.
+    pub(crate) fn ray(
+        &self,
+        start: Point<usize>,
+        direction: impl Into<Vector<isize>> + Copy,
+    ) -> impl Iterator<Item = (Point<usize>, &T)> {
+        std::iter::successors(self.step(start, direction), move |point| {
+            self.step(*point, direction)
+        })
+        .map(|point| (point, &self[point]))
+    }
+
+    pub(crate) fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub(crate) fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Row by row rather than in chunks of the width, which a grid with no columns doesn't have.
+    pub(crate) fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        // Past the last column, stepping by the width would wander into the rows below.
+        assert!(
+            x < self.width,
+            "column {x} is outside a {}x{} grid",
+            self.width,
+            self.height
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Every point, row by row.
+    pub(crate) fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Point::new(index % width, index / width))
+    }
+
+    /// Every cell and its point, row by row.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point, row by row, whose cell matches.
+    pub(crate) fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point<usize>> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub(crate) fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draw the grid with a character per cell, a line per row.
+    #[cfg(test)]
+    pub(crate) fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(&cell));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside a {width}x{height} grid"))
+    }
+}
+
+/// Each cell as it displays, a line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
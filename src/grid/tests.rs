@@ -0,0 +1,118 @@
+use super::Grid;
+use crate::{
+    geometry::{Direction, Direction8, Point},
+    scanner::ScanErrorKind,
+};
+
+const MAP: &str = "30373
+25512
+65332";
+
+fn digits(text: &str) -> Grid<u8> {
+    Grid::parse(text, |c| c.to_digit(10).map(|digit| digit as u8)).unwrap()
+}
+
+#[test]
+fn parse() {
+    let grid = digits(MAP);
+    assert_eq!((5, 3), (grid.width(), grid.height()));
+    assert_eq!(7, grid[Point::new(3, 0)]);
+    assert_eq!(Some(&6), grid.get(Point::new(0, 2)));
+    assert_eq!(None, grid.get(Point::new(5, 0)));
+    assert_eq!(None, grid.get(Point::new(0, 3)));
+    assert_eq!(grid, digits(&MAP.replace('\n', "\r\n")));
+
+    let parse = |text| Grid::parse(text, |c| c.to_digit(10));
+    let error = parse("123\n1x3").unwrap_err();
+    assert_eq!((2, 2), (error.line, error.column));
+    assert_eq!(ScanErrorKind::Unexpected("'x'".to_owned()), error.kind);
+    let error = parse("123\n1234").unwrap_err();
+    assert_eq!(
+        (2, 4, ScanErrorKind::ExpectedEndOfLine),
+        (error.line, error.column, error.kind)
+    );
+    let error = parse("123\n12\n123").unwrap_err();
+    assert_eq!((2, 3), (error.line, error.column));
+    assert_eq!(
+        ScanErrorKind::UnexpectedEndOfInput,
+        parse("").unwrap_err().kind
+    );
+}
+
+#[test]
+fn access() {
+    let mut grid = Grid::new(3, 2, '.');
+    grid[Point::new(2, 1)] = '#';
+    *grid.get_mut(Point::new(0, 0)).unwrap() = '@';
+    assert!(grid.get_mut(Point::new(3, 0)).is_none());
+    grid.row_mut(1)[0] = 'x';
+    assert_eq!("@..\nx.#\n", grid.to_string());
+    assert_eq!(
+        "_..\nx._\n",
+        grid.render(|c| if "@#".contains(*c) { '_' } else { *c })
+    );
+    assert_eq!(Some(Point::new(2, 1)), grid.position(|c| *c == '#'));
+    assert_eq!(None, grid.position(|c| *c == '?'));
+    assert_eq!(
+        "01\n12\n",
+        Grid::from_fn(2, 2, |point| point.x + point.y).to_string()
+    );
+    assert_eq!("100\n101\n", grid.map(|c| u8::from(*c != '.')).to_string());
+}
+
+#[test]
+#[should_panic(expected = "outside a 3x2 grid")]
+fn out_of_bounds() {
+    let grid = Grid::new(3, 2, 0);
+    let _ = grid[Point::new(0, 2)];
+}
+
+#[test]
+#[should_panic(expected = "column 3 is outside a 3x2 grid")]
+fn column_out_of_bounds() {
+    let grid = Grid::new(3, 2, 0);
+    let _ = grid.column(3);
+}
+
+#[test]
+fn iterators() {
+    let grid = digits(MAP);
+    assert_eq!([2, 5, 5, 1, 2][..], *grid.row(1));
+    assert_eq!(vec![&7, &1, &3], grid.column(3).collect::<Vec<_>>());
+    assert_eq!(3, grid.rows().count());
+    let empty: &[u8] = &[];
+    assert_eq!(
+        vec![empty, empty],
+        Grid::new(0, 2, 0).rows().collect::<Vec<_>>()
+    );
+    assert_eq!(15, grid.points().count());
+    assert_eq!(Some((Point::new(4, 2), &2)), grid.iter().last());
+
+    let ray = |start, direction| {
+        grid.ray(start, direction)
+            .map(|(_, height)| *height)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(vec![5, 1, 2], ray(Point::new(1, 1), Direction::Right));
+    assert_eq!(vec![2], ray(Point::new(1, 1), Direction::Left));
+    assert_eq!(vec![0], ray(Point::new(1, 1), Direction::Up));
+    assert!(ray(Point::new(1, 2), Direction::Down).is_empty());
+    assert_eq!(
+        vec![(Point::new(1, 1), &5), (Point::new(2, 2), &3)],
+        grid.ray(Point::new(0, 0), Direction8::DownRight)
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(
+        vec![Point::new(1, 0), Point::new(0, 1)],
+        grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>()
+    );
+    assert_eq!(4, grid.neighbours4(Point::new(1, 1)).count());
+    assert_eq!(3, grid.neighbours8(Point::new(4, 2)).count());
+    assert_eq!(8, grid.neighbours8(Point::new(2, 1)).count());
+    assert_eq!(None, grid.step(Point::new(0, 1), Direction::Left));
+    assert_eq!(
+        Some(Point::new(1, 2)),
+        grid.step(Point::new(0, 1), Direction8::DownRight)
+    );
+}
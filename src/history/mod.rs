@@ -0,0 +1,114 @@
+use crate::{
+    artifact::{self, Query, RunRecord},
+    cli::Args,
+    days::parse_day_number,
+    json,
+    report::format_ms,
+};
+use anyhow::Result;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const USAGE: &str = "history [DAY...] [--from DATE] [--to DATE] [--build-config Debug|Release] [--commit HASH] [--json]";
+
+const HEADERS: [&str; 8] = [
+    "Day", "Run", "Build", "Part 1", "Part 2", "Parse", "Solve", "Commit",
+];
+
+/// A table cell can't span lines, so multi-line answers, such as day 10's picture, are summarised.
+fn answer(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("[{} lines]", answer.trim_end().lines().count())
+    } else {
+        answer.to_owned()
+    }
+}
+
+/// The short hash, marked with `*` if there were uncommitted changes.
+fn commit(run: &RunRecord) -> String {
+    match &run.environment {
+        Some(environment) => {
+            let short = environment.commit.get(..7).unwrap_or(&environment.commit);
+            format!("{short}{}", if environment.dirty { "*" } else { "" })
+        }
+        None => String::new(),
+    }
+}
+
+/// 'runs' as a plain text table with a row per run.
+pub(crate) fn table(runs: &[RunRecord]) -> String {
+    let rows = runs
+        .iter()
+        .map(|run| {
+            [
+                run.day.to_string(),
+                run.timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                run.build_config.clone(),
+                answer(&run.part1),
+                answer(&run.part2),
+                format_ms(run.parse_duration),
+                format_ms(run.duration),
+                commit(run),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let mut widths = HEADERS.map(|header| header.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: &[&str]| {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(index, (cell, width))| {
+                // Numbers line up on the right.
+                if matches!(index, 0 | 5 | 6) {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        line.trim_end().to_owned() + "\n"
+    };
+    let mut table = line(&HEADERS);
+    for row in &rows {
+        table += &line(&row.iter().map(String::as_str).collect::<Vec<_>>());
+    }
+    table
+}
+
+/// 'runs' as a JSON array.
+pub(crate) fn to_json(runs: &[RunRecord]) -> String {
+    let runs = runs.iter().map(json::run).collect::<Vec<_>>();
+    format!("[{}]", runs.join(","))
+}
+
+pub(crate) fn run(args: &[String]) -> Result<()> {
+    let args = Args::parse(args, &["from", "to", "build-config", "commit"], &["json"])?;
+    let query = Query {
+        days: args
+            .positional()
+            .iter()
+            .map(|day| parse_day_number(day))
+            .collect::<Result<_>>()?,
+        from: args.parsed("from")?,
+        to: args.parsed("to")?,
+        build_config: args.value("build-config").map(str::to_owned),
+        commit: args.value("commit").map(str::to_owned),
+    };
+    let runs = artifact::query(artifact::folder(), &query)?;
+    if args.switch("json") {
+        println!("{}", to_json(&runs));
+    } else {
+        print!("{}", table(&runs));
+        println!("{} runs", runs.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use super::{table, to_json};
+use crate::artifact::{Environment, RunRecord};
+use chrono::NaiveDate;
+
+fn run(day: u8, part2: &str, environment: Option<Environment>) -> RunRecord {
+    RunRecord {
+        day,
+        timestamp: NaiveDate::from_ymd_opt(2022, 12, 20)
+            .unwrap()
+            .and_hms_opt(6, 30, 0)
+            .unwrap(),
+        build_config: "Release".to_owned(),
+        part1: "24000".to_owned(),
+        part2: part2.to_owned(),
+        duration: 12.5,
+        parse_duration: 0.25,
+        input: None,
+        environment,
+    }
+}
+
+#[test]
+fn text() {
+    let environment = Environment {
+        commit: "2d163fd6a1b0c3e4".to_owned(),
+        dirty: true,
+        ..Default::default()
+    };
+    let runs = [
+        run(1, "45000", Some(environment)),
+        run(10, "#.\n.#\n", None),
+    ];
+    assert_eq!(
+        "Day  Run                  Build    Part 1  Part 2         Parse     Solve  Commit
+  1  2022-12-20 06:30:00  Release  24000   45000      0.2500 ms  12.50 ms  2d163fd*
+ 10  2022-12-20 06:30:00  Release  24000   [2 lines]  0.2500 ms  12.50 ms
+",
+        table(&runs)
+    );
+}
+
+#[test]
+fn json() {
+    let environment = Environment {
+        commit: "2d163fd".to_owned(),
+        cores: 8,
+        ..Default::default()
+    };
+    let mut first = run(10, "#.\n.#\n", Some(environment));
+    first.input = Some("0123abcd".to_owned());
+    assert_eq!(
+        r##"[{"day":10,"timestamp":"2022-12-20T06:30:00","build_config":"Release","part1":"24000","part2":"#.\n.#\n","parse_ms":0.25,"solve_ms":12.5,"input":"0123abcd","environment":{"commit":"2d163fd","dirty":false,"rustc":"","target":"","features":"","cpu":"","cores":8,"hostname":""}},{"day":1,"timestamp":"2022-12-20T06:30:00","build_config":"Release","part1":"24000","part2":"45000","parse_ms":0.25,"solve_ms":12.5,"input":null,"environment":null}]"##,
+        to_json(&[first, run(1, "45000", None)])
+    );
+    assert_eq!("[]", to_json(&[]));
+}
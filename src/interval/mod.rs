@@ -0,0 +1,235 @@
+//! Runs of consecutive integers, and sets of them kept sorted and merged.
+
+use crate::geometry::Coordinate;
+use std::iter;
+
+#[cfg(test)]
+mod tests;
+
+/// The integers from `start` up to but not including `end`. Puzzles usually give both ends, so
+/// [Interval::inclusive] builds one from those; it's empty when `end <= start`.
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub(crate) struct Interval<T = i32> {
+    pub(crate) start: T,
+    pub(crate) end: T,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl<T: Coordinate> Interval<T> {
+    /// The half-open interval `start..end`.
+    pub(crate) const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The inclusive interval `first..=last`.
+    pub(crate) fn inclusive(first: T, last: T) -> Self {
+        Self::new(first, last + T::ONE)
+    }
+
+    /// The last integer in the interval, which only means something when it isn't empty.
+    #[cfg(test)]
+    pub(crate) fn last(&self) -> T {
+        self.end - T::ONE
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// How many integers the interval holds.
+    pub(crate) fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every integer in 'other' is in this interval too. Everything encloses the empty
+    /// interval.
+    pub(crate) fn encloses(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Whether the intervals have an integer in common.
+    pub(crate) fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Whether the intervals overlap or one starts where the other ends, so that their union
+    /// is an interval.
+    #[cfg(test)]
+    pub(crate) fn touches(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// The integers in both, or `None` if there aren't any.
+    pub(crate) fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// The integers in either, if that's a single interval. Use an [IntervalSet] for the rest.
+    #[cfg(test)]
+    pub(crate) fn union(&self, other: &Self) -> Option<Self> {
+        if other.is_empty() {
+            Some(*self)
+        } else if self.is_empty() {
+            Some(*other)
+        } else {
+            self.touches(other)
+                .then(|| Self::new(self.start.min(other.start), self.end.max(other.end)))
+        }
+    }
+
+    /// The integers in this interval but not 'other': the parts before and after it, either of
+    /// which may be missing.
+    #[cfg(test)]
+    pub(crate) fn difference(&self, other: &Self) -> (Option<Self>, Option<Self>) {
+        let before = Self::new(self.start, self.end.min(other.start));
+        let after = Self::new(self.start.max(other.end), self.end);
+        if other.is_empty() {
+            ((!self.is_empty()).then_some(*self), None)
+        } else {
+            (
+                (!before.is_empty()).then_some(before),
+                (!after.is_empty()).then_some(after),
+            )
+        }
+    }
+}
+
+/// A set of integers stored as sorted, disjoint intervals, merged wherever they touch.
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct IntervalSet<T = i32> {
+    intervals: Vec<Interval<T>>,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl<T: Coordinate> IntervalSet<T> {
+    pub(crate) fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+
+    /// The intervals making up the set, in order.
+    #[cfg(test)]
+    pub(crate) fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.intervals.clear();
+    }
+
+    /// The indices of the intervals that touch 'interval', found by binary search.
+    fn touching(&self, interval: &Interval<T>) -> (usize, usize) {
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|existing| existing.start <= interval.end);
+        (first, last.max(first))
+    }
+
+    /// Add the integers in 'interval', merging it with any it touches.
+    pub(crate) fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let (first, last) = self.touching(&interval);
+        if first == last {
+            self.intervals.insert(first, interval);
+            return;
+        }
+        // Everything from 'first' to 'last' merges into one interval.
+        let merged = Interval::new(
+            interval.start.min(self.intervals[first].start),
+            interval.end.max(self.intervals[last - 1].end),
+        );
+        self.intervals[first] = merged;
+        self.intervals.drain(first + 1..last);
+    }
+
+    /// Take out the integers in 'interval'.
+    #[cfg(test)]
+    pub(crate) fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let (first, last) = self.touching(&interval);
+        let pieces = self.intervals[first..last]
+            .iter()
+            .flat_map(|existing| {
+                let (before, after) = existing.difference(&interval);
+                before.into_iter().chain(after)
+            })
+            .collect::<Vec<_>>();
+        self.intervals.splice(first..last, pieces);
+    }
+
+    #[cfg(test)]
+    pub(crate) fn contains(&self, value: T) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// How many integers the set holds.
+    pub(crate) fn coverage(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |total, interval| total + interval.len())
+    }
+
+    /// The intervals within 'within' that the set doesn't cover, in order.
+    pub(crate) fn gaps(&self, within: Interval<T>) -> impl Iterator<Item = Interval<T>> + '_ {
+        let mut start = within.start;
+        self.intervals
+            .iter()
+            .map(Some)
+            .chain(iter::once(None))
+            .filter_map(move |interval| {
+                let gap = match interval {
+                    Some(interval) => {
+                        let gap = Interval::new(start, interval.start.min(within.end));
+                        start = start.max(interval.end);
+                        gap
+                    }
+                    None => Interval::new(start, within.end),
+                };
+                (!gap.is_empty()).then_some(gap)
+            })
+    }
+}
+
+impl<T: Coordinate> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        set.extend(intervals);
+        set
+    }
+}
+
+impl<T: Coordinate> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, intervals: I) {
+        intervals
+            .into_iter()
+            .for_each(|interval| self.insert(interval));
+    }
+}
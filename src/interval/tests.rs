@@ -0,0 +1,84 @@
+use super::{Interval, IntervalSet};
+
+#[test]
+fn intervals() {
+    let a = Interval::inclusive(2, 8);
+    assert_eq!(Interval::new(2, 9), a);
+    assert_eq!((7, 8), (a.len(), a.last()));
+    assert!(a.contains(8) && !a.contains(9));
+    assert!(Interval::new(3, 3).is_empty());
+    assert_eq!(0, Interval::new(5, 3).len());
+
+    let b = Interval::inclusive(3, 7);
+    assert!(a.encloses(&b) && !b.encloses(&a));
+    assert!(b.encloses(&Interval::new(20, 20)));
+    assert!(a.overlaps(&Interval::inclusive(8, 9)));
+    assert!(!a.overlaps(&Interval::inclusive(9, 10)));
+
+    assert_eq!(Some(b), a.intersection(&b));
+    assert_eq!(None, a.intersection(&Interval::new(9, 12)));
+    // Touching intervals join up, but ones with a gap between don't.
+    assert_eq!(Some(Interval::new(2, 12)), a.union(&Interval::new(9, 12)));
+    assert_eq!(None, a.union(&Interval::new(10, 12)));
+
+    assert_eq!(
+        (Some(Interval::new(2, 3)), Some(Interval::new(8, 9))),
+        a.difference(&b)
+    );
+    assert_eq!((None, None), b.difference(&a));
+    assert_eq!((Some(a), None), a.difference(&Interval::new(20, 30)));
+    assert_eq!((None, Some(a)), a.difference(&Interval::new(-5, 0)));
+}
+
+#[test]
+fn sets() {
+    let mut set = [
+        Interval::new(10, 12),
+        Interval::new(0, 3),
+        Interval::new(5, 6),
+        Interval::new(3, 4),
+    ]
+    .into_iter()
+    .collect::<IntervalSet>();
+    assert_eq!(
+        [
+            Interval::new(0, 4),
+            Interval::new(5, 6),
+            Interval::new(10, 12)
+        ][..],
+        *set.intervals()
+    );
+    assert_eq!(7, set.coverage());
+    assert!(set.contains(5) && set.contains(0) && !set.contains(4) && !set.contains(12));
+
+    // Bridging several intervals merges them all.
+    set.insert(Interval::new(4, 11));
+    assert_eq!([Interval::new(0, 12)][..], *set.intervals());
+
+    set.remove(Interval::new(3, 5));
+    set.remove(Interval::new(11, 20));
+    assert_eq!(
+        [Interval::new(0, 3), Interval::new(5, 11)][..],
+        *set.intervals()
+    );
+    assert_eq!(
+        vec![
+            Interval::new(-2, 0),
+            Interval::new(3, 5),
+            Interval::new(11, 15)
+        ],
+        set.gaps(Interval::new(-2, 15)).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![Interval::new(3, 4)],
+        set.gaps(Interval::new(1, 4)).collect::<Vec<_>>()
+    );
+    assert_eq!(0, set.gaps(Interval::new(6, 9)).count());
+
+    set.clear();
+    assert!(set.is_empty());
+    assert_eq!(
+        vec![Interval::new(0, 4)],
+        set.gaps(Interval::new(0, 4)).collect::<Vec<_>>()
+    );
+}
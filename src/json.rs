@@ -0,0 +1,77 @@
+use crate::{
+    artifact::RunRecord,
+    days::{Solution, SolveError},
+};
+use std::{fmt::Write, time::Duration};
+
+/// Quote and escape a string.
+pub(crate) fn string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c < ' ' => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// A solved input, given the total time including parsing.
+pub(crate) fn solution(day: u8, solution: &Solution, elapsed: Duration) -> String {
+    format!(
+        "{{\"day\":{day},\"part1\":{},\"part2\":{},\"parse_ms\":{},\"solve_ms\":{}}}",
+        string(&solution.part1),
+        string(&solution.part2),
+        solution.parse_duration.as_secs_f64() * 1000.0,
+        elapsed
+            .saturating_sub(solution.parse_duration)
+            .as_secs_f64()
+            * 1000.0
+    )
+}
+
+pub(crate) fn solve_error(day: u8, error: &SolveError) -> String {
+    format!("{{\"day\":{day},\"error\":{}}}", string(&error.to_string()))
+}
+
+pub(crate) fn error(message: &str) -> String {
+    format!("{{\"error\":{}}}", string(message))
+}
+
+/// A run read back from the artifacts. Anything it didn't record is null.
+pub(crate) fn run(run: &RunRecord) -> String {
+    let environment = match &run.environment {
+        Some(environment) => format!(
+            "{{\"commit\":{},\"dirty\":{},\"rustc\":{},\"target\":{},\"features\":{},\"cpu\":{},\"cores\":{},\"hostname\":{}}}",
+            string(&environment.commit),
+            environment.dirty,
+            string(&environment.rustc),
+            string(&environment.target),
+            string(&environment.features),
+            string(&environment.cpu),
+            environment.cores,
+            string(&environment.hostname)
+        ),
+        None => "null".to_owned(),
+    };
+    format!(
+        "{{\"day\":{},\"timestamp\":{},\"build_config\":{},\"part1\":{},\"part2\":{},\"parse_ms\":{},\"solve_ms\":{},\"input\":{},\"environment\":{environment}}}",
+        run.day,
+        string(&run.timestamp.format("%Y-%m-%dT%H:%M:%S").to_string()),
+        string(&run.build_config),
+        string(&run.part1),
+        string(&run.part2),
+        run.parse_duration,
+        run.duration,
+        run.input.as_deref().map_or("null".to_owned(), string)
+    )
+}
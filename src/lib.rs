@@ -0,0 +1,216 @@
+//! Advent of Code 2022. The binary is a thin wrapper around [run], and the solvers are also
+//! exported over a C ABI, see `ffi`.
+
+use anyhow::{anyhow, Result};
+use std::time::{Duration, Instant};
+
+macro_rules! solve {
+    ($day:ident) => {{
+        let timer = Instant::now();
+        let (part1, part2, parse_duration) = $day::solve();
+        let duration = timer.elapsed() - parse_duration;
+        println!("{} = {}, {}", stringify!($day), part1, part2);
+        println!("time = {} ms", duration.as_secs_f64() * 1000.0);
+        println!();
+
+        if records_runs() {
+            artifact::make_artifact(
+                Some(stringify!($day)),
+                artifact::RUN_DESCRIPTION,
+                &["part1", "part2", "duration", "parse_duration", "input"],
+                &[
+                    part1.to_string(),
+                    part2.to_string(),
+                    (duration.as_secs_f64() * 1000.0).to_string(),
+                    (parse_duration.as_secs_f64() * 1000.0).to_string(),
+                    artifact::fingerprint($day::input::INPUT),
+                ],
+            );
+        }
+
+        duration
+    }};
+}
+
+mod artifact;
+mod cli;
+mod compare;
+mod csv;
+mod days;
+mod drift;
+mod ffi;
+mod generate;
+mod geometry;
+mod grid;
+mod history;
+mod interval;
+mod json;
+mod puzzle_page;
+mod report;
+mod retention;
+mod scale;
+mod scanner;
+mod serve;
+mod timings;
+mod today;
+mod visualize;
+
+#[cfg(feature = "day01")]
+mod day01;
+#[cfg(feature = "day02")]
+mod day02;
+#[cfg(feature = "day03")]
+mod day03;
+#[cfg(feature = "day04")]
+mod day04;
+#[cfg(feature = "day05")]
+mod day05;
+#[cfg(feature = "day06")]
+mod day06;
+#[cfg(feature = "day07")]
+mod day07;
+#[cfg(feature = "day08")]
+mod day08;
+#[cfg(feature = "day09")]
+mod day09;
+#[cfg(feature = "day10")]
+mod day10;
+#[cfg(feature = "day11")]
+mod day11;
+#[cfg(feature = "day12")]
+mod day12;
+#[cfg(feature = "day13")]
+mod day13;
+#[cfg(feature = "day14")]
+mod day14;
+#[cfg(feature = "day15")]
+mod day15;
+#[cfg(feature = "day16")]
+mod day16;
+
+/// Whether solving a day records a run artifact. Visualizing sleeps between terminal frames or
+/// writes images inside the timed code, so those timings would skew every command that reads runs.
+fn records_runs() -> bool {
+    visualize::Settings::current().output.is_none()
+}
+
+/// Solve one day's puzzle input, if that day is enabled, returning how long it took.
+fn solve_day(day: u8) -> Option<Duration> {
+    let duration = match day {
+        #[cfg(feature = "day01")]
+        1 => Some(solve!(day01)),
+        #[cfg(feature = "day02")]
+        2 => Some(solve!(day02)),
+        #[cfg(feature = "day03")]
+        3 => Some(solve!(day03)),
+        #[cfg(feature = "day04")]
+        4 => Some(solve!(day04)),
+        #[cfg(feature = "day05")]
+        5 => Some(solve!(day05)),
+        #[cfg(feature = "day06")]
+        6 => Some(solve!(day06)),
+        #[cfg(feature = "day07")]
+        7 => Some(solve!(day07)),
+        #[cfg(feature = "day08")]
+        8 => Some(solve!(day08)),
+        #[cfg(feature = "day09")]
+        9 => Some(solve!(day09)),
+        #[cfg(feature = "day10")]
+        10 => Some(solve!(day10)),
+        #[cfg(feature = "day11")]
+        11 => Some(solve!(day11)),
+        #[cfg(feature = "day12")]
+        12 => Some(solve!(day12)),
+        #[cfg(feature = "day13")]
+        13 => Some(solve!(day13)),
+        #[cfg(feature = "day14")]
+        14 => Some(solve!(day14)),
+        #[cfg(feature = "day15")]
+        15 => Some(solve!(day15)),
+        #[cfg(feature = "day16")]
+        16 => Some(solve!(day16)),
+        _ => None,
+    }?;
+    if records_runs() {
+        drift::warn(day);
+    }
+    Some(duration)
+}
+
+fn solve_all() {
+    let total_duration = (1..=25).filter_map(solve_day).sum::<Duration>();
+    println!(
+        "Total time for 2022: {} ms",
+        total_duration.as_secs_f64() * 1000.0
+    );
+}
+
+fn usage() -> String {
+    format!(
+        "usage: aoc-2022 {} {} [COMMAND]\n\nWith no command, solve every enabled day. Artifacts are read from and written to \
+         --artifacts-dir, ${} or ./{}.\nWith --visualize, days that can be pictured play in the terminal or write numbered \
+         images to --frames-dir or ./{}, and no runs are recorded.\n\ncommands:\n  {}",
+        artifact::USAGE,
+        visualize::USAGE,
+        artifact::FOLDER_VARIABLE,
+        artifact::ARTIFACTS_FOLDER,
+        visualize::FRAMES_FOLDER,
+        [
+            scale::USAGE,
+            puzzle_page::USAGE,
+            today::USAGE,
+            serve::USAGE,
+            compare::USAGE,
+            drift::USAGE,
+            report::USAGE,
+            retention::PRUNE_USAGE,
+            retention::COMPACT_USAGE,
+            timings::USAGE,
+            history::USAGE,
+            #[cfg(feature = "day01")]
+            day01::USAGE,
+            #[cfg(feature = "day02")]
+            day02::USAGE,
+        ]
+        .join("\n  ")
+    )
+}
+
+/// Run the command line, given the arguments after the program name.
+pub fn run(args: &[String]) -> Result<()> {
+    let (settings, args) =
+        artifact::Settings::from_args(args, std::env::var(artifact::FOLDER_VARIABLE).ok())?;
+    settings.apply();
+    let (visualize, args) = visualize::Settings::from_args(args)?;
+    match args.first().map(String::as_str) {
+        // Only solving days visualizes: the other commands solve inputs of their own.
+        None => {
+            visualize.apply();
+            solve_all();
+            Ok(())
+        }
+        Some("scale") => scale::run(&args[1..]),
+        Some("examples") => puzzle_page::run(&args[1..]),
+        Some("today") => {
+            visualize.apply();
+            today::run(&args[1..], solve_day)
+        }
+        Some("serve") => serve::run(&args[1..]),
+        Some("compare") => compare::run(&args[1..]),
+        Some("drift") => drift::run(&args[1..]),
+        Some("report") => report::run(&args[1..]),
+        Some("prune") => retention::run_prune(&args[1..]),
+        Some("compact") => retention::run_compact(&args[1..]),
+        Some("timings") => timings::run(&args[1..]),
+        Some("history") => history::run(&args[1..]),
+        #[cfg(feature = "day01")]
+        Some("elves") => day01::run(&args[1..]),
+        #[cfg(feature = "day02")]
+        Some("strategy") => day02::run(&args[1..]),
+        Some("help" | "--help" | "-h") => {
+            println!("{}", usage());
+            Ok(())
+        }
+        Some(command) => Err(anyhow!("Unknown command: {command}\n\n{}", usage())),
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{cli::Args, days::parse_day_number};
+use anyhow::{anyhow, Context, Result};
+use std::{fs, path::Path};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const USAGE: &str = "examples DAY PAGE.html [--write] [--src DIR] [--for-parts N,N]";
+
+/// What a saved puzzle description page tells us about the examples.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct PuzzlePage {
+    /// Every `<pre><code>` block, as plain text.
+    pub(crate) examples: Vec<String>,
+    /// The expected answer for each part: the last emphasized `<code>` in each part's article.
+    pub(crate) answers: Vec<String>,
+}
+
+/// Replace the handful of entities the puzzle pages use, plus numeric character references.
+fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let replacement = entity.and_then(|(name, end)| {
+            let c = match name {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                    None => name
+                        .strip_prefix('#')
+                        .and_then(|decimal| decimal.parse::<u32>().ok())
+                        .and_then(char::from_u32),
+                },
+            };
+            c.map(|c| (c, end))
+        });
+        match replacement {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Drop tags such as the `<em>` used to highlight parts of an example.
+fn strip_tags(s: &str) -> String {
+    let mut stripped = String::with_capacity(s.len());
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+    stripped
+}
+
+fn text(html: &str) -> String {
+    decode_entities(&strip_tags(html))
+}
+
+/// Every piece of 'html' between 'open' and 'close'.
+fn between<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut pieces = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+        let Some(end) = rest.find(close) else {
+            break;
+        };
+        pieces.push(&rest[..end]);
+        rest = &rest[end + close.len()..];
+    }
+    pieces
+}
+
+/// 'html' with everything from 'open' to 'close' removed.
+fn without(html: &str, open: &str, close: &str) -> String {
+    let mut kept = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        kept.push_str(&rest[..start]);
+        rest = match rest[start..].find(close) {
+            Some(end) => &rest[start + end + close.len()..],
+            None => "",
+        };
+    }
+    kept.push_str(rest);
+    kept
+}
+
+pub(crate) fn parse(html: &str) -> PuzzlePage {
+    let examples = between(html, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(|block| text(block).trim_end_matches('\n').to_owned())
+        .collect();
+    let answers = between(html, "<article", "</article>")
+        .into_iter()
+        .filter_map(|article| {
+            // Only the prose states answers; examples can have emphasized parts too.
+            let prose = without(article, "<pre>", "</pre>");
+            let emphasized = between(&prose, "<code><em>", "</em></code>");
+            let emphasized = if emphasized.is_empty() {
+                between(&prose, "<em><code>", "</code></em>")
+            } else {
+                emphasized
+            };
+            emphasized.last().map(|answer| text(answer))
+        })
+        .collect();
+    PuzzlePage { examples, answers }
+}
+
+/// A string literal in the style of the hand-written tests: real newlines rather than escapes.
+fn literal(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Which example each part's answer is for, counting from 0. 'for_parts' counts from 1, as the
+/// page does, and by default every part uses the first example.
+fn examples_for_parts(page: &PuzzlePage, for_parts: Option<Vec<usize>>) -> Result<Vec<usize>> {
+    if page.answers.is_empty() {
+        return Ok(vec![]);
+    }
+    if page.examples.is_empty() {
+        return Err(anyhow!(
+            "The page has no examples to check the answers against"
+        ));
+    }
+    let for_parts = for_parts.unwrap_or_else(|| vec![1; page.answers.len()]);
+    if for_parts.len() != page.answers.len() {
+        return Err(anyhow!(
+            "The page has {} answers, so --for-parts needs {} examples",
+            page.answers.len(),
+            page.answers.len()
+        ));
+    }
+    for_parts
+        .into_iter()
+        .map(|example| {
+            (1..=page.examples.len())
+                .contains(&example)
+                .then(|| example - 1)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "There are only examples 1 to {}, not {example}",
+                        page.examples.len()
+                    )
+                })
+        })
+        .collect()
+}
+
+/// The examples and answers, with a test per answer solving the example in 'for_parts' for it.
+pub(crate) fn examples_module(page: &PuzzlePage, for_parts: &[usize]) -> String {
+    let mut module = String::from(
+        "// Generated by `aoc-2022 examples` from the saved puzzle page. Regenerate rather than edit.\n\n",
+    );
+    module.push_str("pub(super) const EXAMPLES: &[&str] = &[\n");
+    for example in &page.examples {
+        module.push_str(&format!("    {},\n", literal(example)));
+    }
+    module.push_str("];\n\npub(super) const ANSWERS: &[&str] = &[");
+    module.push_str(
+        &page
+            .answers
+            .iter()
+            .map(|answer| literal(answer))
+            .collect::<Vec<_>>()
+            .join(", "),
+    );
+    module.push_str("];\n");
+    for (part, example) in (1..).zip(for_parts) {
+        module.push_str(&format!(
+            "\n#[test]\nfn part{part}() {{\n    \
+             let solution = super::solve_input(EXAMPLES[{example}]).unwrap();\n    \
+             assert_eq!(ANSWERS[{}], solution.part{part});\n}}\n",
+            part - 1
+        ));
+    }
+    // Match the line endings of the rest of the day sources.
+    module.replace('\n', "\r\n")
+}
+
+/// Declare the examples module next to the tests module, if it isn't already.
+fn declare_examples_module(mod_rs: &str) -> Option<String> {
+    if mod_rs.contains("mod examples;") {
+        return None;
+    }
+    let tests = "#[cfg(test)]\r\nmod tests;";
+    mod_rs.find(tests).map(|index| {
+        let mut declared = mod_rs.to_owned();
+        declared.insert_str(index, "#[cfg(test)]\r\nmod examples;\r\n\r\n");
+        declared
+    })
+}
+
+pub(crate) fn run(args: &[String]) -> Result<()> {
+    let args = Args::parse(args, &["src", "for-parts"], &["write"])?;
+    let [day, page_path] = args.positional() else {
+        return Err(anyhow!("usage: {USAGE}"));
+    };
+    let day = parse_day_number(day)?;
+    let html = fs::read_to_string(page_path).with_context(|| format!("Reading {page_path}"))?;
+    let page = parse(&html);
+    let for_parts = examples_for_parts(&page, args.list("for-parts")?)?;
+
+    for (index, example) in page.examples.iter().enumerate() {
+        println!("example {}:\n{example}\n", index + 1);
+    }
+    for (part, (answer, example)) in page.answers.iter().zip(&for_parts).enumerate() {
+        println!(
+            "part {} answer: {answer} (example {})",
+            part + 1,
+            example + 1
+        );
+    }
+
+    if args.switch("write") {
+        let day_dir = Path::new(args.value("src").unwrap_or("src")).join(format!("day{day:02}"));
+        let examples_path = day_dir.join("examples.rs");
+        fs::write(&examples_path, examples_module(&page, &for_parts))
+            .with_context(|| format!("Writing {}", examples_path.display()))?;
+        println!("wrote {}", examples_path.display());
+
+        let mod_path = day_dir.join("mod.rs");
+        let mod_rs = fs::read_to_string(&mod_path)
+            .with_context(|| format!("Reading {}", mod_path.display()))?;
+        if let Some(mod_rs) = declare_examples_module(&mod_rs) {
+            fs::write(&mod_path, mod_rs)
+                .with_context(|| format!("Writing {}", mod_path.display()))?;
+            println!("declared the examples module in {}", mod_path.display());
+        }
+    }
+    Ok(())
+}
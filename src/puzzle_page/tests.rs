@@ -0,0 +1,111 @@
+use super::{
+    declare_examples_module, decode_entities, examples_for_parts, examples_module, parse,
+    PuzzlePage,
+};
+
+const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 13: Distress Signal ---</h2>
+<p>For example:</p>
+<pre><code>[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+</code></pre>
+<p>Here, the pairs in the right order are <code><em>1</em></code> and <code>2</code>; the sum of these indices is <code><em>13</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>5340</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>[[2]]
+[[6]]
+<em>[1,1,3,1,1]</em>
+</code></pre>
+<p>The decoder key for this distress signal is <code><em>140</em></code>.</p>
+</article>
+<pre><code>Sensor at x=2, y=18: &lt;beacon&gt; &amp; more</code></pre>
+</main>
+</body>
+</html>"#;
+
+#[test]
+fn extract() {
+    let page = parse(PAGE);
+    assert_eq!(
+        vec![
+            "[1,1,3,1,1]\n[1,1,5,1,1]\n\n[[1],[2,3,4]]\n[[1],4]",
+            "[[2]]\n[[6]]\n[1,1,3,1,1]",
+            "Sensor at x=2, y=18: <beacon> & more",
+        ],
+        page.examples
+    );
+    assert_eq!(vec!["13", "140"], page.answers);
+}
+
+#[test]
+fn entities() {
+    assert_eq!(
+        "<a> & \"b\" 'c' A A",
+        decode_entities("&lt;a&gt; &amp; &quot;b&quot; &#39;c&#x27; &#65; &#x41;")
+    );
+    assert_eq!("a & b &unknown; &", decode_entities("a & b &unknown; &"));
+}
+
+#[test]
+fn module() {
+    let page = PuzzlePage {
+        examples: vec!["A Y\nB \"X\"".to_owned(), "C Z".to_owned()],
+        answers: vec!["15".to_owned(), "12".to_owned()],
+    };
+    assert_eq!(
+        "// Generated by `aoc-2022 examples` from the saved puzzle page. Regenerate rather than edit.\r\n\r\n\
+         pub(super) const EXAMPLES: &[&str] = &[\r\n    \"A Y\r\nB \\\"X\\\"\",\r\n    \"C Z\",\r\n];\r\n\r\n\
+         pub(super) const ANSWERS: &[&str] = &[\"15\", \"12\"];\r\n\
+         \r\n\
+         #[test]\r\n\
+         fn part1() {\r\n    \
+         let solution = super::solve_input(EXAMPLES[0]).unwrap();\r\n    \
+         assert_eq!(ANSWERS[0], solution.part1);\r\n\
+         }\r\n\
+         \r\n\
+         #[test]\r\n\
+         fn part2() {\r\n    \
+         let solution = super::solve_input(EXAMPLES[1]).unwrap();\r\n    \
+         assert_eq!(ANSWERS[1], solution.part2);\r\n\
+         }\r\n",
+        examples_module(&page, &[0, 1])
+    );
+
+    let mod_rs = "mod input;\r\n\r\n#[cfg(test)]\r\nmod tests;\r\n";
+    let declared = declare_examples_module(mod_rs).unwrap();
+    assert_eq!(
+        "mod input;\r\n\r\n#[cfg(test)]\r\nmod examples;\r\n\r\n#[cfg(test)]\r\nmod tests;\r\n",
+        declared
+    );
+    assert_eq!(None, declare_examples_module(&declared));
+}
+
+#[test]
+fn examples_for_answers() {
+    let page = parse(PAGE);
+    assert_eq!(vec![0, 0], examples_for_parts(&page, None).unwrap());
+    assert_eq!(
+        vec![0, 1],
+        examples_for_parts(&page, Some(vec![1, 2])).unwrap()
+    );
+    assert!(examples_for_parts(&page, Some(vec![1])).is_err());
+    assert!(examples_for_parts(&page, Some(vec![1, 4])).is_err());
+    assert!(examples_for_parts(&page, Some(vec![0, 1])).is_err());
+
+    let no_examples = PuzzlePage {
+        examples: vec![],
+        answers: vec!["1".to_owned()],
+    };
+    assert!(examples_for_parts(&no_examples, None).is_err());
+    assert_eq!(
+        Vec::<usize>::new(),
+        examples_for_parts(&PuzzlePage::default(), None).unwrap()
+    );
+}
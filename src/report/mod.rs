@@ -0,0 +1,311 @@
+use crate::{
+    artifact::{self, latest_by_day, load_all_runs, RunRecord},
+    cli::Args,
+};
+use anyhow::{Context, Result};
+use chrono::NaiveDateTime;
+use std::{collections::BTreeSet, fmt::Write, fs, path::PathBuf};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const USAGE: &str = "report [--output FILE]";
+
+const REPORT_FILE: &str = "report.html";
+
+const BUILD_CONFIGS: [(&str, &str); 2] = [("Debug", "#d95f02"), ("Release", "#1b9e77")];
+
+const CHART_WIDTH: f64 = 640.0;
+const CHART_HEIGHT: f64 = 220.0;
+/// Room for the axis labels.
+const LEFT: f64 = 70.0;
+const BOTTOM: f64 = 30.0;
+const TOP: f64 = 10.0;
+const RIGHT: f64 = 20.0;
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; color: #222; }
+h2 { margin-top: 2em; }
+svg text { font-size: 11px; fill: #444; }
+.axis { stroke: #999; }
+.grid { stroke: #eee; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; vertical-align: top; }
+td.number { text-align: right; }
+pre { margin: 0; font-size: 8px; line-height: 1; }
+.legend span { display: inline-block; width: 12px; height: 12px; margin: 0 4px 0 12px; }";
+
+pub(crate) fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Maps a value onto a range of pixels. Durations span orders of magnitude between days and build
+/// configs, so times use a logarithmic scale.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct LogScale {
+    /// Powers of ten at either end.
+    pub(crate) low: i32,
+    pub(crate) high: i32,
+}
+
+impl LogScale {
+    pub(crate) fn new(values: impl Iterator<Item = f64>) -> Self {
+        let (min, max) = values
+            .filter(|value| *value > 0.0)
+            .fold((f64::MAX, f64::MIN), |(min, max), value| {
+                (min.min(value), max.max(value))
+            });
+        if min > max {
+            return Self { low: 0, high: 1 };
+        }
+        let low = min.log10().floor() as i32;
+        let high = (max.log10().ceil() as i32).max(low + 1);
+        Self { low, high }
+    }
+
+    /// From 0 at the bottom of the scale to 1 at the top.
+    pub(crate) fn fraction(&self, value: f64) -> f64 {
+        let value = value.max(10f64.powi(self.low));
+        (value.log10() - self.low as f64) / (self.high - self.low) as f64
+    }
+}
+
+pub(crate) fn format_ms(ms: f64) -> String {
+    if ms >= 100.0 {
+        format!("{ms:.0} ms")
+    } else if ms >= 1.0 {
+        format!("{ms:.2} ms")
+    } else {
+        format!("{ms:.4} ms")
+    }
+}
+
+/// Run time over time for one day's runs, a line per build config.
+pub(crate) fn day_chart(runs: &[&RunRecord]) -> String {
+    let scale = LogScale::new(runs.iter().map(|run| run.duration));
+    let seconds = |timestamp: NaiveDateTime| timestamp.and_utc().timestamp() as f64;
+    let first = runs
+        .iter()
+        .map(|run| seconds(run.timestamp))
+        .fold(f64::MAX, f64::min);
+    let last = runs
+        .iter()
+        .map(|run| seconds(run.timestamp))
+        .fold(f64::MIN, f64::max);
+    let plot_width = CHART_WIDTH - LEFT - RIGHT;
+    let plot_height = CHART_HEIGHT - TOP - BOTTOM;
+    let x = |run: &RunRecord| {
+        if last > first {
+            LEFT + (seconds(run.timestamp) - first) / (last - first) * plot_width
+        } else {
+            LEFT + plot_width / 2.0
+        }
+    };
+    let y = |ms: f64| TOP + (1.0 - scale.fraction(ms)) * plot_height;
+
+    let mut svg = format!(
+        "<svg width=\"{CHART_WIDTH}\" height=\"{CHART_HEIGHT}\" viewBox=\"0 0 {CHART_WIDTH} {CHART_HEIGHT}\" role=\"img\">\n"
+    );
+    for power in scale.low..=scale.high {
+        let ms = 10f64.powi(power);
+        let _ = writeln!(
+            svg,
+            "<line class=\"grid\" x1=\"{LEFT}\" x2=\"{}\" y1=\"{y:.1}\" y2=\"{y:.1}\"/><text x=\"{}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+            CHART_WIDTH - RIGHT,
+            LEFT - 6.0,
+            y(ms) + 4.0,
+            format_ms(ms),
+            y = y(ms),
+        );
+    }
+    let bottom = CHART_HEIGHT - BOTTOM;
+    let _ = writeln!(
+        svg,
+        "<line class=\"axis\" x1=\"{LEFT}\" x2=\"{}\" y1=\"{bottom}\" y2=\"{bottom}\"/>",
+        CHART_WIDTH - RIGHT
+    );
+    if let (Some(first), Some(last)) = (runs.first(), runs.last()) {
+        let _ = writeln!(
+            svg,
+            "<text x=\"{LEFT}\" y=\"{}\">{}</text><text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>",
+            bottom + 18.0,
+            first.timestamp.format("%Y-%m-%d %H:%M"),
+            CHART_WIDTH - RIGHT,
+            bottom + 18.0,
+            last.timestamp.format("%Y-%m-%d %H:%M"),
+        );
+    }
+
+    for (build_config, color) in BUILD_CONFIGS {
+        let series = runs
+            .iter()
+            .filter(|run| run.build_config == build_config)
+            .collect::<Vec<_>>();
+        let points = series
+            .iter()
+            .map(|run| format!("{:.1},{:.1}", x(run), y(run.duration)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let _ = writeln!(
+            svg,
+            "<polyline fill=\"none\" stroke=\"{color}\" stroke-width=\"2\" points=\"{points}\"/>"
+        );
+        for run in series {
+            let _ = writeln!(
+                svg,
+                "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"3\" fill=\"{color}\"><title>{} {}: {}</title></circle>",
+                x(run),
+                y(run.duration),
+                build_config,
+                run.timestamp,
+                format_ms(run.duration)
+            );
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn day_color(day: u8) -> String {
+    format!("hsl({}, 60%, 55%)", day as u32 * 47 % 360)
+}
+
+/// The year's total time per build config from each day's latest run, stacked by day.
+pub(crate) fn total_chart(runs: &[RunRecord]) -> String {
+    let bars = BUILD_CONFIGS
+        .iter()
+        .map(|(build_config, _)| (*build_config, latest_by_day(runs, Some(build_config))))
+        .filter(|(_, latest)| !latest.is_empty())
+        .collect::<Vec<_>>();
+    let longest = bars
+        .iter()
+        .map(|(_, latest)| {
+            latest
+                .iter()
+                .map(|run| run.duration + run.parse_duration)
+                .sum::<f64>()
+        })
+        .fold(0.0, f64::max);
+    let bar_height = 28.0;
+    let plot_width = CHART_WIDTH - LEFT - RIGHT - 90.0;
+    // Runs too quick to time record 0 ms, so every bar can be empty, leaving nothing to scale by.
+    let scale = if longest > 0.0 {
+        plot_width / longest
+    } else {
+        0.0
+    };
+    let height = bars.len() as f64 * (bar_height + 12.0) + 10.0;
+
+    let mut svg = format!(
+        "<svg width=\"{CHART_WIDTH}\" height=\"{height}\" viewBox=\"0 0 {CHART_WIDTH} {height}\" role=\"img\">\n"
+    );
+    for (index, (build_config, latest)) in bars.iter().enumerate() {
+        let top = 5.0 + index as f64 * (bar_height + 12.0);
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"end\">{build_config}</text>",
+            LEFT - 6.0,
+            top + bar_height / 2.0 + 4.0
+        );
+        let mut x = LEFT;
+        for run in latest {
+            let ms = run.duration + run.parse_duration;
+            let width = ms * scale;
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{x:.2}\" y=\"{top}\" width=\"{width:.2}\" height=\"{bar_height}\" fill=\"{}\"><title>Day {}: {}</title></rect>",
+                day_color(run.day),
+                run.day,
+                format_ms(ms)
+            );
+            x += width;
+        }
+        let total = latest
+            .iter()
+            .map(|run| run.duration + run.parse_duration)
+            .sum::<f64>();
+        let _ = writeln!(
+            svg,
+            "<text x=\"{:.2}\" y=\"{}\">{}</text>",
+            x + 6.0,
+            top + bar_height / 2.0 + 4.0,
+            format_ms(total)
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn answer_cell(answer: &str) -> String {
+    if answer.contains('\n') {
+        format!("<pre>{}</pre>", escape(answer.trim_end()))
+    } else {
+        escape(answer)
+    }
+}
+
+/// The whole report as one HTML file, with nothing to fetch.
+pub(crate) fn render(runs: &[RunRecord], generated: NaiveDateTime) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code 2022 performance</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n<h1>Advent of Code 2022 performance</h1>\n<p>{} runs, generated {}.</p>\n",
+        runs.len(),
+        generated.format("%Y-%m-%d %H:%M")
+    );
+    let legend = BUILD_CONFIGS
+        .iter()
+        .map(|(build_config, color)| {
+            format!("<span style=\"background: {color}\"></span>{build_config}")
+        })
+        .collect::<String>();
+
+    html.push_str("<h2>Year total</h2>\n<p>The latest run of each day, including parsing.</p>\n");
+    html.push_str(&total_chart(runs));
+
+    html.push_str("<h2>Latest answers</h2>\n<table>\n<tr><th>Day</th><th>Part 1</th><th>Part 2</th><th>Build</th><th>Parse</th><th>Solve</th><th>Run</th></tr>\n");
+    for run in latest_by_day(runs, None) {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td class=\"number\">{}</td><td class=\"number\">{}</td><td>{}</td></tr>",
+            run.day,
+            answer_cell(&run.part1),
+            answer_cell(&run.part2),
+            escape(&run.build_config),
+            format_ms(run.parse_duration),
+            format_ms(run.duration),
+            run.timestamp
+        );
+    }
+    html.push_str("</table>\n");
+
+    let days = runs.iter().map(|run| run.day).collect::<BTreeSet<_>>();
+    let _ = writeln!(
+        html,
+        "<h2>Run time by day</h2>\n<p class=\"legend\">Solve time, not counting parsing, on a log scale.{legend}</p>"
+    );
+    for day in days {
+        let day_runs = runs.iter().filter(|run| run.day == day).collect::<Vec<_>>();
+        let _ = writeln!(html, "<h3>Day {day}</h3>");
+        html.push_str(&day_chart(&day_runs));
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+pub(crate) fn run(args: &[String]) -> Result<()> {
+    let args = Args::parse(args, &["output"], &[])?;
+    let output = args
+        .value("output")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| artifact::folder().join(REPORT_FILE));
+    let runs = load_all_runs(artifact::folder())?;
+    let html = render(&runs, chrono::Local::now().naive_local());
+    fs::write(&output, html).with_context(|| format!("Writing {}", output.display()))?;
+    println!(
+        "Wrote a report on {} runs to {}",
+        runs.len(),
+        output.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use super::{day_chart, escape, render, total_chart, LogScale};
+use crate::artifact::RunRecord;
+use chrono::NaiveDate;
+
+fn run(day: u8, hour: u32, build_config: &str, duration: f64, part2: &str) -> RunRecord {
+    RunRecord {
+        day,
+        timestamp: NaiveDate::from_ymd_opt(2022, 12, 20)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap(),
+        build_config: build_config.to_owned(),
+        part1: "<1>".to_owned(),
+        part2: part2.to_owned(),
+        duration,
+        parse_duration: 0.5,
+        input: None,
+        environment: None,
+    }
+}
+
+#[test]
+fn scale() {
+    let scale = LogScale::new([0.02, 0.3, 750.0].into_iter());
+    assert_eq!(LogScale { low: -2, high: 3 }, scale);
+    assert!((scale.fraction(1.0) - 0.4).abs() < 1e-9);
+    assert_eq!(0.0, scale.fraction(0.0));
+    assert_eq!(
+        LogScale { low: 0, high: 1 },
+        LogScale::new([1.0].into_iter())
+    );
+    assert_eq!(LogScale { low: 0, high: 1 }, LogScale::new([].into_iter()));
+}
+
+#[test]
+fn html() {
+    assert_eq!("a &lt;b&gt; &amp; &quot;c&quot;", escape("a <b> & \"c\""));
+
+    let runs = [
+        run(1, 10, "Debug", 2.0, "5"),
+        run(1, 11, "Release", 0.1, "5"),
+        run(10, 12, "Release", 0.7, "#..#\n.##.\n"),
+    ];
+    let chart = day_chart(&runs[..2].iter().collect::<Vec<_>>());
+    assert_eq!(2, chart.matches("<polyline").count());
+    assert_eq!(2, chart.matches("<circle").count());
+
+    let html = render(&runs, runs[2].timestamp);
+    assert!(html.contains("<pre>#..#\n.##.</pre>"));
+    assert!(html.contains("<td>&lt;1&gt;</td>"));
+    assert_eq!(2, html.matches("<h3>").count());
+    // It has to open offline.
+    assert!(!html.contains("src=") && !html.contains("href=") && !html.contains("http"));
+}
+
+#[test]
+fn zero_totals() {
+    let mut runs = [
+        run(1, 10, "Release", 0.0, "5"),
+        run(2, 10, "Release", 0.0, "5"),
+    ];
+    for run in &mut runs {
+        run.parse_duration = 0.0;
+    }
+    let chart = total_chart(&runs);
+    assert_eq!(2, chart.matches("width=\"0.00\"").count());
+    assert!(!chart.contains("NaN"));
+}
@@ -0,0 +1,212 @@
+use crate::{
+    artifact::{self, day_folder, load_day, parse_history, write_history, RunRecord, Source},
+    cli::Args,
+    days::parse_day_number,
+};
+use anyhow::{anyhow, Context, Result};
+use std::{collections::BTreeMap, fs, path::Path};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const PRUNE_USAGE: &str =
+    "prune [DAY...] [--keep-last N] [--keep-best] [--keep-daily] [--dry-run]";
+
+pub(crate) const COMPACT_USAGE: &str = "compact [DAY...] [--dry-run]";
+
+/// Which runs `prune` keeps. A run is kept if any of the policies keeps it, and each policy applies
+/// to each build config separately so Debug runs don't push out Release ones.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Policy {
+    /// The latest N runs.
+    pub(crate) keep_last: Option<usize>,
+    /// The fastest run.
+    pub(crate) keep_best: bool,
+    /// The latest run on each calendar day.
+    pub(crate) keep_daily: bool,
+}
+
+/// Whether to keep each of a day's 'runs', which are oldest first.
+pub(crate) fn retained(runs: &[RunRecord], policy: &Policy) -> Vec<bool> {
+    let mut keep = vec![false; runs.len()];
+    let mut by_build_config = BTreeMap::<&str, Vec<usize>>::new();
+    for (index, run) in runs.iter().enumerate() {
+        by_build_config
+            .entry(&run.build_config)
+            .or_default()
+            .push(index);
+    }
+    for indices in by_build_config.values() {
+        if let Some(last) = policy.keep_last {
+            for &index in indices.iter().rev().take(last) {
+                keep[index] = true;
+            }
+        }
+        if policy.keep_best {
+            if let Some(&best) = indices
+                .iter()
+                .min_by(|a, b| runs[**a].duration.total_cmp(&runs[**b].duration))
+            {
+                keep[best] = true;
+            }
+        }
+        if policy.keep_daily {
+            let mut daily = BTreeMap::new();
+            for &index in indices {
+                daily.insert(runs[index].timestamp.date(), index);
+            }
+            for index in daily.into_values() {
+                keep[index] = true;
+            }
+        }
+    }
+    keep
+}
+
+fn parse_days(args: &Args) -> Result<Vec<u8>> {
+    if args.positional().is_empty() {
+        Ok((1..=25).collect())
+    } else {
+        args.positional()
+            .iter()
+            .map(|day| parse_day_number(day))
+            .collect()
+    }
+}
+
+/// Remove a day's runs that 'policy' doesn't keep, returning how many runs there were and how many
+/// go. Rows are dropped from the history file by rewriting it.
+fn prune_day(folder: &Path, day: u8, policy: &Policy, dry_run: bool) -> Result<(usize, usize)> {
+    let loaded = load_day(folder, day)?;
+    let (runs, sources): (Vec<_>, Vec<_>) = loaded.into_iter().unzip();
+    let keep = retained(&runs, policy);
+    let removed = keep.iter().filter(|keep| !**keep).count();
+    if dry_run || removed == 0 {
+        return Ok((runs.len(), removed));
+    }
+
+    let from_history = sources
+        .iter()
+        .zip(&keep)
+        .any(|(source, keep)| *source == Source::History && !keep);
+    if from_history {
+        let kept = runs
+            .iter()
+            .zip(&sources)
+            .zip(&keep)
+            .filter(|((_, source), keep)| **source == Source::History && **keep)
+            .map(|((run, _), _)| run)
+            .collect::<Vec<_>>();
+        write_history(&day_folder(folder, day).join(artifact::HISTORY_FILE), &kept)?;
+    }
+    for (source, _) in sources.iter().zip(&keep).filter(|(_, keep)| !**keep) {
+        if let Source::Run(path) = source {
+            fs::remove_file(path).with_context(|| format!("Removing {}", path.display()))?;
+        }
+    }
+    Ok((runs.len(), removed))
+}
+
+/// Merge a day's `run_*.csv` files into its history file, returning how many were merged. The
+/// files are only removed once the history file has been read back and found to hold every run.
+fn compact_day(folder: &Path, day: u8, dry_run: bool) -> Result<usize> {
+    let loaded = load_day(folder, day)?;
+    let files = loaded
+        .iter()
+        .filter_map(|(_, source)| match source {
+            Source::Run(path) => Some(path),
+            Source::History => None,
+        })
+        .collect::<Vec<_>>();
+    if dry_run || files.is_empty() {
+        return Ok(files.len());
+    }
+
+    // A compaction that stopped while removing files leaves runs in both places.
+    let mut runs = Vec::<&RunRecord>::new();
+    for (run, _) in &loaded {
+        if !runs.contains(&run) {
+            runs.push(run);
+        }
+    }
+    let path = day_folder(folder, day).join(artifact::HISTORY_FILE);
+    write_history(&path, &runs)?;
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("Reading {}", path.display()))?;
+    let written = parse_history(day, &contents)?;
+    if written.iter().ne(runs.iter().copied()) {
+        return Err(anyhow!(
+            "{} doesn't read back the same; no run files were removed",
+            path.display()
+        ));
+    }
+    for file in &files {
+        fs::remove_file(file).with_context(|| format!("Removing {}", file.display()))?;
+    }
+    Ok(files.len())
+}
+
+pub(crate) fn run_prune(args: &[String]) -> Result<()> {
+    let args = Args::parse(
+        args,
+        &["keep-last"],
+        &["keep-best", "keep-daily", "dry-run"],
+    )?;
+    let policy = Policy {
+        keep_last: args.parsed("keep-last")?,
+        keep_best: args.switch("keep-best"),
+        keep_daily: args.switch("keep-daily"),
+    };
+    // Keeping none would remove every run, which is never what pruning is for.
+    if policy.keep_last == Some(0) {
+        return Err(anyhow!("--keep-last must be at least 1"));
+    }
+    if policy == Policy::default() {
+        return Err(anyhow!(
+            "Say which runs to keep with --keep-last, --keep-best or --keep-daily"
+        ));
+    }
+    let dry_run = args.switch("dry-run");
+
+    let folder = artifact::folder();
+    let mut total = 0;
+    for day in parse_days(&args)? {
+        let (runs, removed) = prune_day(folder, day, &policy, dry_run)?;
+        if removed > 0 {
+            println!(
+                "Day {day}: {} {removed} of {runs} runs",
+                if dry_run { "would remove" } else { "removed" }
+            );
+        }
+        total += removed;
+    }
+    println!(
+        "{} {total} runs in total.",
+        if dry_run { "Would remove" } else { "Removed" }
+    );
+    Ok(())
+}
+
+pub(crate) fn run_compact(args: &[String]) -> Result<()> {
+    let args = Args::parse(args, &[], &["dry-run"])?;
+    let dry_run = args.switch("dry-run");
+
+    let folder = artifact::folder();
+    let mut total = 0;
+    for day in parse_days(&args)? {
+        let merged = compact_day(folder, day, dry_run)?;
+        if merged > 0 {
+            println!(
+                "Day {day}: {} {merged} run files into {}",
+                if dry_run { "would merge" } else { "merged" },
+                artifact::HISTORY_FILE
+            );
+        }
+        total += merged;
+    }
+    println!(
+        "{} {total} run files in total.",
+        if dry_run { "Would merge" } else { "Merged" }
+    );
+    Ok(())
+}
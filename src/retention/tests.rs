@@ -0,0 +1,159 @@
+use super::{compact_day, prune_day, retained, run_prune, Policy};
+use crate::artifact::{load_runs, RunRecord, HISTORY_FILE};
+use chrono::NaiveDate;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn run(build_config: &str, day_of_month: u32, hour: u32, duration: f64) -> RunRecord {
+    RunRecord {
+        day: 1,
+        timestamp: NaiveDate::from_ymd_opt(2022, 12, day_of_month)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap(),
+        build_config: build_config.to_owned(),
+        part1: "1".to_owned(),
+        part2: "2".to_owned(),
+        duration,
+        parse_duration: 0.1,
+        input: None,
+        environment: None,
+    }
+}
+
+#[test]
+fn policies() {
+    let runs = [
+        run("Debug", 1, 10, 9.0),
+        run("Release", 1, 11, 1.0),
+        run("Debug", 1, 12, 8.0),
+        run("Release", 2, 10, 3.0),
+        run("Release", 2, 11, 2.0),
+        run("Debug", 3, 10, 7.5),
+    ];
+    let keep = |policy| {
+        retained(&runs, &policy)
+            .into_iter()
+            .enumerate()
+            .filter(|(_, keep)| *keep)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>()
+    };
+
+    assert!(keep(Policy::default()).is_empty());
+    // Per build config, so the old Release run doesn't go to make room for Debug runs.
+    let last = Policy {
+        keep_last: Some(1),
+        ..Default::default()
+    };
+    assert_eq!([4, 5][..], keep(last));
+    let best = Policy {
+        keep_best: true,
+        ..Default::default()
+    };
+    assert_eq!([1, 5][..], keep(best));
+    let daily = Policy {
+        keep_daily: true,
+        ..Default::default()
+    };
+    assert_eq!([1, 2, 4, 5][..], keep(daily));
+    assert_eq!(
+        [1, 2, 4, 5][..],
+        keep(Policy {
+            keep_last: Some(1),
+            keep_best: true,
+            keep_daily: true,
+        })
+    );
+    assert_eq!(
+        [0, 1, 2, 3, 4, 5][..],
+        keep(Policy {
+            keep_last: Some(10),
+            ..Default::default()
+        })
+    );
+}
+
+fn scratch_folder(name: &str) -> PathBuf {
+    let folder = env::temp_dir().join(format!("aoc-2022-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&folder);
+    fs::create_dir_all(folder.join("day10")).unwrap();
+    let checked_in = Path::new(env!("CARGO_MANIFEST_DIR")).join("artifacts/day10");
+    for entry in fs::read_dir(checked_in).unwrap() {
+        let path = entry.unwrap().path();
+        fs::copy(&path, folder.join("day10").join(path.file_name().unwrap())).unwrap();
+    }
+    folder
+}
+
+fn file_count(folder: &Path) -> usize {
+    fs::read_dir(folder.join("day10")).unwrap().count()
+}
+
+#[test]
+fn compact_and_prune() {
+    // Day 10 has multi-line answers and artifacts from before inputs and environments were
+    // recorded, so it's the easiest to lose data from.
+    let folder = scratch_folder("compact");
+    let before = load_runs(&folder, 10).unwrap();
+    assert_eq!(4, before.len());
+
+    assert_eq!(4, compact_day(&folder, 10, true).unwrap());
+    assert_eq!(4, file_count(&folder));
+    assert_eq!(4, compact_day(&folder, 10, false).unwrap());
+    assert_eq!(1, file_count(&folder));
+    assert!(folder.join("day10").join(HISTORY_FILE).exists());
+    assert_eq!(before, load_runs(&folder, 10).unwrap());
+    assert_eq!(0, compact_day(&folder, 10, false).unwrap());
+
+    // New runs go to their own files again, and are merged with the history the next time.
+    fs::copy(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("artifacts/day10/run_2022-12-13_23-11-19.csv"),
+        folder.join("day10/run_2022-12-14_08-00-00.csv"),
+    )
+    .unwrap();
+    assert_eq!(5, load_runs(&folder, 10).unwrap().len());
+    assert_eq!(1, compact_day(&folder, 10, false).unwrap());
+    let after = load_runs(&folder, 10).unwrap();
+    assert_eq!(before[..], after[..4]);
+
+    // Three Debug runs on the 13th, and Release runs on the 13th and 14th, all in the history file
+    // now.
+    let daily = Policy {
+        keep_daily: true,
+        ..Default::default()
+    };
+    assert_eq!((5, 2), prune_day(&folder, 10, &daily, true).unwrap());
+    assert_eq!(5, load_runs(&folder, 10).unwrap().len());
+    assert_eq!((5, 2), prune_day(&folder, 10, &daily, false).unwrap());
+    assert_eq!(after[2..], load_runs(&folder, 10).unwrap()[..]);
+
+    fs::remove_dir_all(&folder).unwrap();
+}
+
+#[test]
+fn prune_run_files() {
+    let folder = scratch_folder("prune");
+    let last = Policy {
+        keep_last: Some(1),
+        ..Default::default()
+    };
+    let before = load_runs(&folder, 10).unwrap();
+    // Three Debug runs and then a Release run.
+    assert_eq!((4, 2), prune_day(&folder, 10, &last, false).unwrap());
+    assert_eq!(2, file_count(&folder));
+    assert_eq!(before[2..], load_runs(&folder, 10).unwrap()[..]);
+
+    fs::remove_dir_all(&folder).unwrap();
+}
+
+#[test]
+fn keep_last_zero() {
+    let args = ["--keep-last", "0", "--keep-best"].map(str::to_owned);
+    assert_eq!(
+        "--keep-last must be at least 1",
+        run_prune(&args).unwrap_err().to_string()
+    );
+}
@@ -0,0 +1,147 @@
+use crate::{
+    cli::Args,
+    days::{parse_day_number, Day, DAYS},
+    generate::Rng,
+};
+use anyhow::{anyhow, Result};
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const USAGE: &str = "scale [DAY...] [--sizes 1,10,100] [--seed N] [--budget SECONDS]";
+
+/// Keep re-running quick solves until this much time has passed, so small sizes aren't all noise.
+const MIN_SAMPLE_TIME: Duration = Duration::from_millis(50);
+
+type Growth = fn(f64) -> f64;
+
+/// Growth functions to fit measurements against, from slowest to fastest growing. The logarithms
+/// are offset so they stay positive at a scale of 1.
+const COMPLEXITY_CLASSES: [(&str, Growth); 6] = [
+    ("O(1)", |_| 1.0),
+    ("O(log n)", |n| 1.0 + n.ln()),
+    ("O(n)", |n| n),
+    ("O(n log n)", |n| n * (1.0 + n.ln())),
+    ("O(n^2)", |n| n * n),
+    ("O(n^3)", |n| n * n * n),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Sample {
+    /// The generator's scale factor. This tracks the amount of work better than the input's length,
+    /// which for some days is dominated by fixed text.
+    pub(crate) size: f64,
+    pub(crate) seconds: f64,
+}
+
+/// The slope of log(time) against log(size) by least squares.
+pub(crate) fn log_log_slope(samples: &[Sample]) -> Option<f64> {
+    if samples.len() < 2 {
+        return None;
+    }
+    let points = samples
+        .iter()
+        .map(|sample| (sample.size.ln(), sample.seconds.ln()))
+        .collect::<Vec<_>>();
+    let count = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let covariance = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum::<f64>();
+    let variance = points
+        .iter()
+        .map(|(x, _)| (x - mean_x).powi(2))
+        .sum::<f64>();
+    if variance == 0.0 {
+        None
+    } else {
+        Some(covariance / variance)
+    }
+}
+
+/// The complexity class whose growth best explains the samples: the one for which
+/// time / f(size) varies least (in log space).
+pub(crate) fn estimate_complexity(samples: &[Sample]) -> Option<&'static str> {
+    if samples.len() < 2 {
+        return None;
+    }
+    COMPLEXITY_CLASSES
+        .iter()
+        .map(|(name, f)| {
+            let residuals = samples
+                .iter()
+                .map(|sample| sample.seconds.ln() - f(sample.size).ln())
+                .collect::<Vec<_>>();
+            let mean = residuals.iter().sum::<f64>() / residuals.len() as f64;
+            let spread = residuals.iter().map(|r| (r - mean).powi(2)).sum::<f64>();
+            (name, spread)
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(name, _)| *name)
+}
+
+fn time_solve(day: &Day, input: &str) -> Result<Duration> {
+    let mut runs = 0;
+    let timer = Instant::now();
+    loop {
+        (day.solve_input)(input)?;
+        runs += 1;
+        let elapsed = timer.elapsed();
+        if elapsed >= MIN_SAMPLE_TIME {
+            break Ok(elapsed / runs);
+        }
+    }
+}
+
+pub(crate) fn run(args: &[String]) -> Result<()> {
+    let args = Args::parse(args, &["sizes", "seed", "budget"], &[])?;
+    let sizes = args.list::<usize>("sizes")?.unwrap_or(vec![1, 10, 100]);
+    if sizes.contains(&0) {
+        // A generator has nothing to scale down to, and the fit takes logarithms of the sizes.
+        return Err(anyhow!("Sizes start at 1"));
+    }
+    let seed = args.parsed::<u64>("seed")?.unwrap_or(2022);
+    let budget = Duration::from_secs_f64(args.parsed::<f64>("budget")?.unwrap_or(10.0));
+    let selected = args
+        .positional()
+        .iter()
+        .map(|day| parse_day_number(day))
+        .collect::<Result<Vec<_>>>()?;
+
+    for day in DAYS
+        .iter()
+        .filter(|day| selected.is_empty() || selected.contains(&day.number))
+    {
+        let mut samples = vec![];
+        for &scale in &sizes {
+            let input = (day.generate)(&mut Rng::new(seed), scale);
+            let duration = time_solve(day, &input)?;
+            println!(
+                "day{:02} {:>5}x {:>12} bytes {:>12.3} ms",
+                day.number,
+                scale,
+                input.len(),
+                duration.as_secs_f64() * 1000.0
+            );
+            samples.push(Sample {
+                size: scale as f64,
+                seconds: duration.as_secs_f64(),
+            });
+            if duration > budget {
+                println!("day{:02} over budget, skipping larger sizes", day.number);
+                break;
+            }
+        }
+        match (estimate_complexity(&samples), log_log_slope(&samples)) {
+            (Some(complexity), Some(slope)) => {
+                println!("day{:02} ~{complexity} (slope {slope:.2})", day.number)
+            }
+            _ => println!("day{:02} not enough sizes to estimate", day.number),
+        }
+        println!();
+    }
+    Ok(())
+}
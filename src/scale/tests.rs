@@ -0,0 +1,58 @@
+use super::{estimate_complexity, log_log_slope, run, Sample};
+use crate::{days::DAYS, generate::Rng};
+
+fn samples(f: impl Fn(f64) -> f64) -> Vec<Sample> {
+    [1.0, 10.0, 100.0, 1000.0]
+        .into_iter()
+        .map(|size| Sample {
+            size,
+            seconds: 1e-9 * f(size),
+        })
+        .collect()
+}
+
+#[test]
+fn complexity() {
+    assert_eq!(Some("O(n)"), estimate_complexity(&samples(|n| 3.0 * n)));
+    assert_eq!(
+        Some("O(n log n)"),
+        estimate_complexity(&samples(|n| n * (1.0 + n.ln())))
+    );
+    assert_eq!(Some("O(n^2)"), estimate_complexity(&samples(|n| n * n + n)));
+    assert_eq!(Some("O(1)"), estimate_complexity(&samples(|_| 5.0)));
+    assert_eq!(None, estimate_complexity(&samples(|n| n)[..1]));
+
+    let slope = log_log_slope(&samples(|n| n * n)).unwrap();
+    assert!((slope - 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for day in DAYS {
+        let input = (day.generate)(&mut Rng::new(7), 1);
+        let solution = match (day.solve_input)(&input) {
+            Ok(solution) => solution,
+            Err(error) => panic!("day {}: {error}", day.number),
+        };
+        assert!(
+            !solution.part1.is_empty() && !solution.part2.is_empty(),
+            "day {}: {solution:?}",
+            day.number
+        );
+        // The same input gets the same answers.
+        let again = (day.solve_input)(&input).unwrap();
+        assert_eq!(
+            (&solution.part1, &solution.part2),
+            (&again.part1, &again.part2),
+            "day {}",
+            day.number
+        );
+    }
+}
+
+#[test]
+fn sizes() {
+    let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+    let error = run(&args(&["--sizes", "1,0"])).unwrap_err();
+    assert_eq!("Sizes start at 1", error.to_string());
+}
@@ -0,0 +1,333 @@
+use std::{error::Error, fmt, str::FromStr};
+
+#[cfg(test)]
+mod tests;
+
+/// An integer type that the scanner can accumulate one decimal digit at a time.
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) trait Integer: Copy {
+    const ZERO: Self;
+    const SIGNED: bool;
+
+    /// Shift in a digit on the positive side: self * 10 + digit.
+    fn push_digit(self, digit: u8) -> Option<Self>;
+
+    /// Shift in a digit on the negative side: self * 10 - digit.
+    /// Accumulating negatively means the most negative value can be represented.
+    fn push_negative_digit(self, digit: u8) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($signed:literal, $($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const SIGNED: bool = $signed;
+
+            #[inline]
+            fn push_digit(self, digit: u8) -> Option<Self> {
+                self.checked_mul(10)?.checked_add(digit as Self)
+            }
+
+            #[inline]
+            fn push_negative_digit(self, digit: u8) -> Option<Self> {
+                self.checked_mul(10)?.checked_sub(digit as Self)
+            }
+        }
+    )*};
+}
+
+impl_integer!(false, u8, u16, u32, u64, usize);
+impl_integer!(true, i8, i16, i32, i64, isize);
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ScanErrorKind {
+    ExpectedDigit,
+    Overflow,
+    ExpectedLiteral(String),
+    ExpectedEndOfLine,
+    UnexpectedEndOfInput,
+    Unexpected(String),
+}
+
+impl fmt::Display for ScanErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScanErrorKind::ExpectedDigit => write!(f, "expected a digit"),
+            ScanErrorKind::Overflow => write!(f, "integer overflow"),
+            ScanErrorKind::ExpectedLiteral(literal) => write!(f, "expected {literal:?}"),
+            ScanErrorKind::ExpectedEndOfLine => write!(f, "expected end of line"),
+            ScanErrorKind::UnexpectedEndOfInput => write!(f, "unexpected end of input"),
+            ScanErrorKind::Unexpected(what) => write!(f, "unexpected {what}"),
+        }
+    }
+}
+
+/// A scan failure. Line and column are 1-based.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ScanError {
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+    pub(crate) kind: ScanErrorKind,
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl Error for ScanError {}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl ScanError {
+    /// Move an error found in a piece of text to where that text sits in the input: 'lines'
+    /// further down, and on the text's first line, 'columns' further along.
+    pub(crate) fn offset(mut self, lines: usize, columns: usize) -> Self {
+        if self.line == 1 {
+            self.column += columns;
+        }
+        self.line += lines;
+        self
+    }
+}
+
+/// A scan failure in a day's input, with the line it happened on so it can be shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    pub(crate) day: u8,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+    pub(crate) snippet: String,
+    pub(crate) kind: ScanErrorKind,
+}
+
+impl ParseError {
+    /// Place 'error', from scanning 'input', in the given day.
+    #[cfg_attr(not(all_days), allow(dead_code))]
+    pub(crate) fn new(day: u8, input: &str, error: ScanError) -> Self {
+        let snippet = input.lines().nth(error.line - 1).unwrap_or_default();
+        Self::at_line(day, snippet, error)
+    }
+
+    /// Place 'error' in the given day, where 'snippet' is the line it's on. For inputs read a
+    /// line at a time, which aren't around as a whole.
+    pub(crate) fn at_line(day: u8, snippet: &str, error: ScanError) -> Self {
+        Self {
+            day,
+            line: error.line,
+            column: error.column,
+            snippet: snippet.trim_end_matches(['\r', '\n']).to_owned(),
+            kind: error.kind,
+        }
+    }
+}
+
+/// The position and problem, then the line with a caret under the column.
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.kind
+        )?;
+        writeln!(f, "  {}", self.snippet)?;
+        write!(f, "  {:>column$}", "^", column = self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse each line of 'input' with its `FromStr`, placing errors on the line they came from.
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) fn parse_lines<T: FromStr<Err = ScanError>>(input: &str) -> Result<Vec<T>, ScanError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|error: ScanError| error.offset(index, 0))
+        })
+        .collect()
+}
+
+/// A forward-only cursor over the bytes of an input, tracking the current line so errors can say
+/// where they happened. It only ever stops between characters.
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Debug)]
+pub(crate) struct Scanner<'a> {
+    input: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+    line: usize,
+    line_start: usize,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl<'a> Scanner<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            bytes: input.as_bytes(),
+            position: 0,
+            line: 1,
+            line_start: 0,
+        }
+    }
+
+    /// Number lines from 'line' instead of 1, for scanning a section that starts partway into an input.
+    pub(crate) fn starting_at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub(crate) fn line(&self) -> usize {
+        self.line
+    }
+
+    pub(crate) fn column(&self) -> usize {
+        self.position - self.line_start + 1
+    }
+
+    /// Build an error of the given kind at the current position.
+    pub(crate) fn error(&self, kind: ScanErrorKind) -> ScanError {
+        ScanError {
+            line: self.line,
+            column: self.column(),
+            kind,
+        }
+    }
+
+    pub(crate) fn is_at_end(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    /// True at a newline or at the end of the input.
+    pub(crate) fn is_at_line_end(&self) -> bool {
+        matches!(self.peek(), None | Some(b'\n') | Some(b'\r'))
+    }
+
+    /// Consume and return the next character, however many bytes it takes.
+    pub(crate) fn char(&mut self) -> Result<char, ScanError> {
+        let c = self.input[self.position..]
+            .chars()
+            .next()
+            .ok_or_else(|| self.error(ScanErrorKind::UnexpectedEndOfInput))?;
+        self.advance(c.len_utf8());
+        Ok(c)
+    }
+
+    fn advance(&mut self, count: usize) {
+        for _ in 0..count {
+            if self.bytes[self.position] == b'\n' {
+                self.line += 1;
+                self.line_start = self.position + 1;
+            }
+            self.position += 1;
+        }
+    }
+
+    pub(crate) fn skip_spaces(&mut self) {
+        while let Some(b' ' | b'\t') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    /// Consume 'literal' if the input continues with it.
+    pub(crate) fn eat_literal(&mut self, literal: &str) -> bool {
+        if self.bytes[self.position..].starts_with(literal.as_bytes()) {
+            self.advance(literal.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn expect_literal(&mut self, literal: &str) -> Result<(), ScanError> {
+        if self.eat_literal(literal) {
+            Ok(())
+        } else {
+            Err(self.error(ScanErrorKind::ExpectedLiteral(literal.to_owned())))
+        }
+    }
+
+    /// Consume a line terminator ("\n" or "\r\n"). The end of the input also counts.
+    pub(crate) fn end_of_line(&mut self) -> Result<(), ScanError> {
+        if self.is_at_end() || self.eat_literal("\n") || self.eat_literal("\r\n") {
+            Ok(())
+        } else {
+            Err(self.error(ScanErrorKind::ExpectedEndOfLine))
+        }
+    }
+
+    /// Consume a line terminator that another line has to follow. If the input ends here instead,
+    /// the error is placed at the start of the missing line rather than the end of this one.
+    pub(crate) fn next_line(&mut self) -> Result<(), ScanError> {
+        if self.is_at_end() {
+            return Err(ScanError {
+                line: self.line + 1,
+                column: 1,
+                kind: ScanErrorKind::UnexpectedEndOfInput,
+            });
+        }
+        self.end_of_line()
+    }
+
+    /// Consume everything up to (but not including) the next newline and return it.
+    pub(crate) fn rest_of_line(&mut self) -> &'a str {
+        let start = self.position;
+        while !self.is_at_line_end() {
+            self.position += 1;
+        }
+        // Line ends are ASCII, so this stops between characters too.
+        &self.input[start..self.position]
+    }
+
+    fn digits<T: Integer>(&mut self, negative: bool) -> Result<T, ScanError> {
+        let start = self.position;
+        let mut value = T::ZERO;
+        while let Some(byte @ b'0'..=b'9') = self.peek() {
+            let digit = byte - b'0';
+            let next = if negative {
+                value.push_negative_digit(digit)
+            } else {
+                value.push_digit(digit)
+            };
+            value = next.ok_or_else(|| ScanError {
+                line: self.line,
+                column: start - self.line_start + 1,
+                kind: ScanErrorKind::Overflow,
+            })?;
+            self.position += 1;
+        }
+        if self.position == start {
+            Err(self.error(ScanErrorKind::ExpectedDigit))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Consume an unsigned decimal integer.
+    pub(crate) fn unsigned<T: Integer>(&mut self) -> Result<T, ScanError> {
+        self.digits(false)
+    }
+
+    /// Consume a decimal integer with an optional leading '-' or '+'.
+    pub(crate) fn signed<T: Integer>(&mut self) -> Result<T, ScanError> {
+        if T::SIGNED && self.peek() == Some(b'-') {
+            self.position += 1;
+            self.digits(true)
+        } else {
+            self.eat_literal("+");
+            self.digits(false)
+        }
+    }
+}
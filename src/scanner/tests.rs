@@ -0,0 +1,296 @@
+use super::{parse_lines, ParseError, ScanError, ScanErrorKind, Scanner};
+use crate::days::DAYS;
+use std::{hint::black_box, time::Instant};
+
+#[test]
+fn integers() {
+    let mut scanner = Scanner::new("42 -17 +5 18446744073709551615");
+    assert_eq!(42, scanner.unsigned::<u32>().unwrap());
+    scanner.skip_spaces();
+    assert_eq!(-17, scanner.signed::<i32>().unwrap());
+    scanner.skip_spaces();
+    assert_eq!(5, scanner.signed::<i8>().unwrap());
+    scanner.skip_spaces();
+    assert_eq!(u64::MAX, scanner.unsigned::<u64>().unwrap());
+    assert!(scanner.is_at_end());
+
+    assert_eq!(i8::MIN, Scanner::new("-128").signed::<i8>().unwrap());
+    assert_eq!(
+        ScanErrorKind::Overflow,
+        Scanner::new("128").signed::<i8>().unwrap_err().kind
+    );
+    assert_eq!(
+        ScanErrorKind::ExpectedDigit,
+        Scanner::new("-x").signed::<i64>().unwrap_err().kind
+    );
+    // Unsigned scans do not accept a sign.
+    assert_eq!(
+        ScanErrorKind::ExpectedDigit,
+        Scanner::new("-1").signed::<u8>().unwrap_err().kind
+    );
+}
+
+#[test]
+fn characters() {
+    let mut scanner = Scanner::new("éa b€\nc");
+    assert_eq!('é', scanner.char().unwrap());
+    assert_eq!(3, scanner.column());
+    assert_eq!('a', scanner.char().unwrap());
+    assert_eq!(" b€", scanner.rest_of_line());
+    scanner.end_of_line().unwrap();
+    assert_eq!('c', scanner.char().unwrap());
+    assert_eq!(
+        ScanErrorKind::UnexpectedEndOfInput,
+        scanner.char().unwrap_err().kind
+    );
+}
+
+#[test]
+fn literals_and_lines() {
+    let mut scanner = Scanner::new("move 1 from 2\nmove x");
+    scanner.expect_literal("move ").unwrap();
+    assert_eq!(1, scanner.unsigned::<usize>().unwrap());
+    assert!(!scanner.eat_literal(" to "));
+    scanner.expect_literal(" from ").unwrap();
+    assert_eq!(2, scanner.unsigned::<usize>().unwrap());
+    scanner.end_of_line().unwrap();
+    scanner.expect_literal("move ").unwrap();
+    assert_eq!(
+        ScanError {
+            line: 2,
+            column: 6,
+            kind: ScanErrorKind::ExpectedDigit
+        },
+        scanner.unsigned::<usize>().unwrap_err()
+    );
+    assert_eq!("x", scanner.rest_of_line());
+    scanner.end_of_line().unwrap();
+    assert!(scanner.is_at_end());
+
+    let mut scanner = Scanner::new("a\nb");
+    scanner.expect_literal("a").unwrap();
+    scanner.next_line().unwrap();
+    scanner.expect_literal("b").unwrap();
+    assert_eq!(
+        ScanError {
+            line: 3,
+            column: 1,
+            kind: ScanErrorKind::UnexpectedEndOfInput
+        },
+        scanner.next_line().unwrap_err()
+    );
+
+    let error = Scanner::new("abc").expect_literal("abd").unwrap_err();
+    assert_eq!("line 1, column 1: expected \"abd\"", error.to_string());
+}
+
+#[test]
+fn parse_errors() {
+    // Errors from each line are placed on the line they came from.
+    assert_eq!(
+        vec![Digit(1), Digit(2)],
+        parse_lines::<Digit>("1\n2").unwrap()
+    );
+    let error = parse_lines::<Digit>("1\n2\n3x").unwrap_err();
+    assert_eq!((3, 2), (error.line, error.column));
+
+    // Only the first line of a piece of text moves along.
+    let error = |line| ScanError {
+        line,
+        column: 3,
+        kind: ScanErrorKind::ExpectedDigit,
+    };
+    assert_eq!(
+        ScanError {
+            column: 9,
+            ..error(4)
+        },
+        error(1).offset(3, 6)
+    );
+    assert_eq!(error(5), error(2).offset(3, 6));
+
+    let input = "addx 3\naddx y\nnoop";
+    let error = ParseError::new(
+        10,
+        input,
+        ScanError {
+            line: 2,
+            column: 6,
+            kind: ScanErrorKind::ExpectedDigit,
+        },
+    );
+    assert_eq!("addx y", error.snippet);
+    assert_eq!(
+        "day 10, line 2, column 6: expected a digit\n  addx y\n       ^",
+        error.to_string()
+    );
+}
+
+/// Returns how many records it parsed, so the work can't be optimized away.
+type SplitParser = fn(&str) -> usize;
+
+/// The split-and-parse code the ported days used before the scanner, for [parse_benchmark].
+const SPLIT_PARSERS: [(u8, SplitParser); 6] = [
+    (1, |input| {
+        let mut inventories = vec![];
+        let mut inventory = vec![];
+        for line in input.lines() {
+            if line.is_empty() {
+                inventories.push(std::mem::take(&mut inventory));
+            } else {
+                inventory.push(line.parse::<usize>().unwrap());
+            }
+        }
+        inventories.push(inventory);
+        inventories.len()
+    }),
+    (4, |input| {
+        let range = |s: &str| {
+            let mut section_range = s.split('-').map(|s| s.parse::<u8>().unwrap());
+            (section_range.next().unwrap(), section_range.next().unwrap())
+        };
+        input
+            .lines()
+            .map(|line| {
+                let mut elves = line.split(',').map(range);
+                (elves.next().unwrap(), elves.next().unwrap())
+            })
+            .collect::<Vec<_>>()
+            .len()
+    }),
+    (5, |input| {
+        let mut sections = input.split("\n\n");
+        let initial_stack_state = sections.next().unwrap();
+        let stack_count = initial_stack_state
+            .lines()
+            .last()
+            .unwrap()
+            .split_whitespace()
+            .count();
+        let mut stacks = vec![Vec::new(); stack_count];
+        initial_stack_state.lines().for_each(|line| {
+            let crates = line.chars().skip(1).step_by(4);
+            stacks
+                .iter_mut()
+                .zip(crates)
+                .for_each(|(stack, stacked_crate)| stack.push(stacked_crate));
+        });
+        stacks.iter_mut().for_each(|stack| {
+            stack.pop();
+            stack.reverse();
+            stack.retain(|&c| c != ' ');
+        });
+        let steps = sections
+            .next()
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let mut tokens = line.split(' ').skip(1).step_by(2);
+                let mut next = || tokens.next().unwrap().parse::<usize>().unwrap();
+                (next(), next() - 1, next() - 1)
+            })
+            .collect::<Vec<_>>();
+        stacks.len() + steps.len()
+    }),
+    (9, |input| {
+        input
+            .lines()
+            .map(|line| {
+                let mut tokens = line.split_whitespace();
+                let direction = match tokens.next().unwrap() {
+                    "R" => (1, 0),
+                    "L" => (-1, 0),
+                    "U" => (0, -1),
+                    "D" => (0, 1),
+                    _ => panic!(),
+                };
+                let distance = tokens.next().unwrap().parse::<usize>().unwrap();
+                (direction, distance)
+            })
+            .collect::<Vec<_>>()
+            .len()
+    }),
+    (14, |input| {
+        let point = |s: &str| {
+            let mut tokens = s.split(',');
+            let x = tokens.next().unwrap().parse::<usize>().unwrap();
+            let y = tokens.next().unwrap().parse::<usize>().unwrap();
+            (x, y)
+        };
+        input
+            .lines()
+            .map(|line| line.split(" -> ").map(point).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+            .len()
+    }),
+    (15, |input| {
+        let point = |s: &str| {
+            let x_index = s.match_indices("x=").next().unwrap().0;
+            let mut tokens = s[x_index..].split(',');
+            let x = tokens.next().unwrap()[2..].parse::<i32>().unwrap();
+            let y = tokens.next().unwrap()[3..].parse::<i32>().unwrap();
+            (x, y)
+        };
+        input
+            .lines()
+            .map(|line| {
+                let mut parts = line.split(':');
+                let (sensor, beacon) = (point(parts.next().unwrap()), point(parts.next().unwrap()));
+                (
+                    sensor,
+                    sensor.0.abs_diff(beacon.0) + sensor.1.abs_diff(beacon.1),
+                )
+            })
+            .collect::<Vec<_>>()
+            .len()
+    }),
+];
+
+/// Time each ported day's parser on its real input against the code it replaced, taking the
+/// fastest of several runs of each. The scanner's times are the parse times the days report, which
+/// for day 1 include finding the answers, since it streams, and for day 15 collecting the beacons.
+/// Only the days in the build are timed.
+/// Run with `cargo test --release --all-features parse_benchmark -- --ignored --nocapture`.
+#[test]
+#[ignore]
+fn parse_benchmark() {
+    const RUNS: usize = 20;
+    println!("day  split + parse  scanner");
+    for (number, split_parser) in SPLIT_PARSERS {
+        let Some(day) = DAYS.iter().find(|day| day.number == number) else {
+            continue;
+        };
+        let split = (0..RUNS)
+            .map(|_| {
+                let timer = Instant::now();
+                black_box(split_parser(black_box(day.input)));
+                timer.elapsed()
+            })
+            .min()
+            .unwrap_or_default();
+        let scanner = (0..RUNS)
+            .map(|_| (day.solve_input)(day.input).unwrap().parse_duration)
+            .min()
+            .unwrap_or_default();
+        println!(
+            "{number:>3}  {:>10.3} ms  {:>7.3} ms",
+            split.as_secs_f64() * 1000.0,
+            scanner.as_secs_f64() * 1000.0
+        );
+    }
+}
+
+/// A line holding a number, for [parse_lines].
+#[derive(Debug, PartialEq, Eq)]
+struct Digit(u8);
+
+impl std::str::FromStr for Digit {
+    type Err = ScanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut scanner = Scanner::new(s);
+        let digit = Self(scanner.unsigned()?);
+        scanner.end_of_line()?;
+        Ok(digit)
+    }
+}
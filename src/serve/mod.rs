@@ -0,0 +1,273 @@
+use crate::{
+    cli::Args,
+    days::{parse_day_number, Day, DAYS},
+    json,
+};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Ipv4Addr, Shutdown, TcpListener, TcpStream},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const USAGE: &str = "serve [--port N] [--max-body BYTES] [--workers N]";
+
+const DEFAULT_PORT: u16 = 2022;
+
+/// Generous next to a real puzzle input, which is tens of kilobytes.
+const DEFAULT_MAX_BODY: usize = 1 << 20;
+
+/// Limit on the request line and headers together.
+const MAX_HEAD: usize = 8 << 10;
+
+/// Drop clients that stall, so they can't hold a thread forever.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How many connections are handled at once. More wait to be accepted.
+const DEFAULT_WORKERS: usize = 16;
+
+/// How long, and how much of a rejected request, to read and discard before closing.
+const LINGER_TIMEOUT: Duration = Duration::from_secs(1);
+const LINGER_LIMIT: u64 = 16 << 20;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Request {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) body: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Response {
+    pub(crate) status: u16,
+    /// Always JSON.
+    pub(crate) body: String,
+}
+
+impl Response {
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: json::error(message),
+        }
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            408 => "Request Timeout",
+            411 => "Length Required",
+            413 => "Content Too Large",
+            422 => "Unprocessable Content",
+            431 => "Request Header Fields Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+
+    fn write_to(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body
+        )?;
+        writer.flush()
+    }
+}
+
+/// Read one request, or the error response to send instead. Only `Content-Length` bodies are
+/// supported, which is all a client posting a puzzle input needs.
+pub(crate) fn read_request(
+    reader: &mut impl BufRead,
+    max_body: usize,
+) -> Result<Request, Response> {
+    let mut head = Vec::new();
+    loop {
+        let start = head.len();
+        let read = (&mut *reader)
+            .take((MAX_HEAD + 1 - start) as u64)
+            .read_until(b'\n', &mut head)
+            .map_err(|error| match error.kind() {
+                std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => {
+                    Response::error(408, "Timed out reading the request")
+                }
+                _ => Response::error(400, &error.to_string()),
+            })?;
+        if head.len() > MAX_HEAD {
+            return Err(Response::error(431, "Request head is too large"));
+        }
+        if read == 0 {
+            return Err(Response::error(400, "Incomplete request"));
+        }
+        if matches!(&head[start..], b"\r\n" | b"\n") {
+            break;
+        }
+    }
+    let head = String::from_utf8(head).map_err(|_| Response::error(400, "Request is not UTF-8"))?;
+    let mut lines = head.lines();
+
+    let request_line = lines.next().unwrap_or_default();
+    let mut parts = request_line.split(' ');
+    let (Some(method), Some(path), Some(version), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(Response::error(400, "Bad request line"));
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(Response::error(400, "Only HTTP/1.x is supported"));
+    }
+
+    let mut content_length = None;
+    for line in lines.take_while(|line| !line.is_empty()) {
+        let Some((name, value)) = line.split_once(':') else {
+            return Err(Response::error(400, "Bad header"));
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = Some(
+                value
+                    .parse::<usize>()
+                    .map_err(|_| Response::error(400, "Bad Content-Length"))?,
+            );
+        } else if name.eq_ignore_ascii_case("transfer-encoding") {
+            return Err(Response::error(411, "Send a Content-Length instead"));
+        }
+    }
+
+    let body = match content_length {
+        Some(length) if length > max_body => {
+            return Err(Response::error(
+                413,
+                &format!("Body is over the limit of {max_body} bytes"),
+            ))
+        }
+        Some(length) => {
+            let mut body = vec![0; length];
+            reader
+                .read_exact(&mut body)
+                .map_err(|_| Response::error(400, "Body is shorter than Content-Length"))?;
+            String::from_utf8(body).map_err(|_| Response::error(400, "Body is not UTF-8"))?
+        }
+        None if method == "POST" => return Err(Response::error(411, "Content-Length is required")),
+        None => String::new(),
+    };
+
+    Ok(Request {
+        method: method.to_owned(),
+        path: path.to_owned(),
+        body,
+    })
+}
+
+/// Route a request. `POST /day/{n}` solves the body as that day's input.
+pub(crate) fn respond(request: &Request, days: &[Day]) -> Response {
+    let Some(day) = request.path.strip_prefix("/day/") else {
+        return Response::error(404, "Not found");
+    };
+    let Some(day) = parse_day_number(day)
+        .ok()
+        .and_then(|number| days.iter().find(|day| day.number == number))
+    else {
+        return Response::error(404, &format!("Day {day} isn't enabled in this build"));
+    };
+    if request.method != "POST" {
+        return Response::error(405, "Use POST");
+    }
+
+    match day.solve_untrusted(&request.body) {
+        Ok((solution, elapsed)) => Response {
+            status: 200,
+            body: json::solution(day.number, &solution, elapsed),
+        },
+        Err(error) => Response {
+            status: 422,
+            body: json::solve_error(day.number, &error),
+        },
+    }
+}
+
+fn handle_connection(stream: TcpStream, days: &[Day], max_body: usize) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    match read_request(&mut reader, max_body) {
+        Ok(request) => respond(&request, days).write_to(&mut &stream),
+        Err(response) => {
+            // Part of the request may be unread, such as a body over the limit. Closing with data
+            // unread resets the connection, which can lose the response before the client reads
+            // it, so finish sending and discard the rest for a while first.
+            response.write_to(&mut &stream)?;
+            stream.shutdown(Shutdown::Write)?;
+            stream.set_read_timeout(Some(LINGER_TIMEOUT))?;
+            let _ = io::copy(&mut reader.take(LINGER_LIMIT), &mut io::sink());
+            Ok(())
+        }
+    }
+}
+
+/// Accept connections forever, handling them on 'workers' threads. Connections beyond those
+/// queue up to be accepted.
+pub(crate) fn serve(listener: TcpListener, days: &'static [Day], max_body: usize, workers: usize) {
+    // Holding no streams itself, the channel blocks accepting until a worker is free.
+    let (sender, receiver) = mpsc::sync_channel::<TcpStream>(0);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..workers {
+        let receiver = Arc::clone(&receiver);
+        thread::spawn(move || loop {
+            let Ok(stream) = receiver.lock().unwrap().recv() else {
+                return;
+            };
+            if let Err(error) = handle_connection(stream, days, max_body) {
+                eprintln!("connection error: {error}");
+            }
+        });
+    }
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                if sender.send(stream).is_err() {
+                    return;
+                }
+            }
+            Err(error) => eprintln!("accept error: {error}"),
+        }
+    }
+}
+
+pub(crate) fn run(args: &[String]) -> Result<()> {
+    // Solvers panic on some malformed inputs, which is only reported as an error if it unwinds.
+    if cfg!(panic = "abort") {
+        return Err(anyhow!(
+            "This build aborts on panic, so one bad input would stop the server. Build it with \
+             --profile release-unwind instead."
+        ));
+    }
+    let args = Args::parse(args, &["port", "max-body", "workers"], &[])?;
+    let port = args.parsed::<u16>("port")?.unwrap_or(DEFAULT_PORT);
+    let max_body = args
+        .parsed::<usize>("max-body")?
+        .unwrap_or(DEFAULT_MAX_BODY);
+    let workers = args.parsed::<usize>("workers")?.unwrap_or(DEFAULT_WORKERS);
+    if workers == 0 {
+        return Err(anyhow!("--workers must be at least 1"));
+    }
+
+    // Localhost only: this is for tools on the same machine, not the network.
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .with_context(|| format!("Couldn't listen on port {port}"))?;
+    println!(
+        "Listening on http://{}, POST an input to /day/{{n}}",
+        listener.local_addr()?
+    );
+    serve(listener, DAYS, max_body, workers);
+    Ok(())
+}
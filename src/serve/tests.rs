@@ -0,0 +1,143 @@
+use super::{read_request, serve, Request};
+use crate::{
+    days::{Day, Solution},
+    json,
+};
+use anyhow::{anyhow, Result};
+use std::{
+    io::{Read, Write},
+    net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Sums the numbers in the input, slowly, so overlapping requests show up in the timings.
+fn solve_sum(input: &str) -> Result<Solution> {
+    let numbers = input
+        .lines()
+        .map(|line| {
+            line.parse::<i64>()
+                .map_err(|_| anyhow!("Not a number: {line}"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    thread::sleep(Duration::from_millis(200));
+    Ok(Solution::new(
+        numbers.iter().sum::<i64>(),
+        numbers.len(),
+        Duration::ZERO,
+    ))
+}
+
+const TEST_DAYS: &[Day] = &[Day {
+    number: 1,
+    solve_input: solve_sum,
+    input: "",
+    generate: |_, _| String::new(),
+}];
+
+fn start(max_body: usize, workers: usize) -> SocketAddr {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || serve(listener, TEST_DAYS, max_body, workers));
+    address
+}
+
+fn post(address: SocketAddr, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(
+        stream,
+        "POST {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let status = response[9..12].parse().unwrap();
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+    (status, body.to_owned())
+}
+
+#[test]
+fn requests() {
+    let request = read_request(
+        &mut "POST /day/7 HTTP/1.1\r\ncontent-length: 3\r\n\r\n1\n2extra".as_bytes(),
+        100,
+    )
+    .unwrap();
+    assert_eq!(
+        Request {
+            method: "POST".to_owned(),
+            path: "/day/7".to_owned(),
+            body: "1\n2".to_owned(),
+        },
+        request
+    );
+
+    let status = |request: &str| {
+        read_request(&mut request.as_bytes(), 100)
+            .unwrap_err()
+            .status
+    };
+    assert_eq!(
+        413,
+        status("POST /day/1 HTTP/1.1\r\nContent-Length: 101\r\n\r\n")
+    );
+    assert_eq!(411, status("POST /day/1 HTTP/1.1\r\n\r\n"));
+    assert_eq!(400, status("POST /day/1\r\n\r\n"));
+    assert_eq!(
+        400,
+        status("POST /day/1 HTTP/1.1\r\nContent-Length: 5\r\n\r\n1")
+    );
+    assert_eq!(
+        431,
+        status(&format!("GET /{} HTTP/1.1\r\n\r\n", "x".repeat(9000)))
+    );
+
+    assert_eq!(r#""a\"b\\c\nd\u0001""#, json::string("a\"b\\c\nd\u{1}"));
+}
+
+#[test]
+fn server() {
+    let address = start(64, 8);
+
+    let (status, body) = post(address, "/day/1", "1\n2\n3");
+    assert_eq!(200, status);
+    assert!(body.starts_with(r#"{"day":1,"part1":"6","part2":"3","parse_ms":0,"solve_ms":"#));
+
+    assert_eq!(
+        (422, r#"{"day":1,"error":"Not a number: x"}"#.to_owned()),
+        post(address, "/day/01", "1\nx")
+    );
+    assert_eq!(404, post(address, "/day/2", "").0);
+    assert_eq!(404, post(address, "/days", "").0);
+    assert_eq!(413, post(address, "/day/1", &"1\n".repeat(40)).0);
+    // Far more than the socket buffers hold, so the server has to take it all to be heard.
+    assert_eq!(413, post(address, "/day/1", &"1\n".repeat(1 << 20)).0);
+
+    // Each solve sleeps for 200 ms, so these only finish in time if they're handled concurrently.
+    let timer = Instant::now();
+    let clients = (0..8)
+        .map(|i| thread::spawn(move || post(address, "/day/1", &i.to_string())))
+        .collect::<Vec<_>>();
+    for (i, client) in clients.into_iter().enumerate() {
+        let (status, body) = client.join().unwrap();
+        assert_eq!(200, status);
+        assert!(body.contains(&format!(r#""part1":"{i}""#)));
+    }
+    assert!(timer.elapsed() < Duration::from_millis(1000));
+}
+
+#[test]
+fn workers() {
+    let address = start(64, 2);
+
+    // Two workers sleeping 200 ms a request take two rounds for four requests.
+    let timer = Instant::now();
+    let clients = (0..4)
+        .map(|i| thread::spawn(move || post(address, "/day/1", &i.to_string())))
+        .collect::<Vec<_>>();
+    for client in clients {
+        assert_eq!(200, client.join().unwrap().0);
+    }
+    assert!(timer.elapsed() >= Duration::from_millis(400));
+}
@@ -0,0 +1,102 @@
+use crate::{
+    artifact::{self, latest_by_day, load_all_runs, RunRecord},
+    cli::Args,
+    report::format_ms,
+};
+use anyhow::{anyhow, Context, Result};
+use std::{fmt::Write, fs};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const USAGE: &str = "timings [FILE] [--redact]";
+
+/// The table goes between these lines of the Markdown file, replacing whatever was there.
+pub(crate) const BEGIN_MARKER: &str = "<!-- aoc-timings:begin -->";
+pub(crate) const END_MARKER: &str = "<!-- aoc-timings:end -->";
+
+/// Only release runs are fair to publish.
+const BUILD_CONFIG: &str = "Release";
+
+/// An answer as a table cell. Multi-line answers, such as day 10's picture, don't fit in a cell.
+fn answer_cell(answer: &str, redact: bool) -> String {
+    if redact {
+        "*hidden*".to_owned()
+    } else if answer.contains('\n') {
+        format!("*{}-line picture*", answer.trim_end().lines().count())
+    } else {
+        format!("`{}`", answer.replace('|', "\\|"))
+    }
+}
+
+/// A Markdown table of each day's latest release run from 'runs', with a total.
+pub(crate) fn table(runs: &[RunRecord], redact: bool) -> String {
+    let latest = latest_by_day(runs, Some(BUILD_CONFIG));
+    let mut table =
+        "| Day | Part 1 | Part 2 | Parse | Solve |\n|---:|---|---|---:|---:|\n".to_owned();
+    for run in &latest {
+        let _ = writeln!(
+            table,
+            "| {} | {} | {} | {} | {} |",
+            run.day,
+            answer_cell(&run.part1, redact),
+            answer_cell(&run.part2, redact),
+            format_ms(run.parse_duration),
+            format_ms(run.duration)
+        );
+    }
+    let _ = writeln!(
+        table,
+        "| **Total** | | | **{}** | **{}** |",
+        format_ms(latest.iter().map(|run| run.parse_duration).sum()),
+        format_ms(latest.iter().map(|run| run.duration).sum())
+    );
+    table
+}
+
+/// Replace what's between the markers in 'document' with 'table', keeping the markers and the
+/// document's line endings.
+pub(crate) fn replace_section(document: &str, table: &str) -> Result<String> {
+    let begin = document
+        .find(BEGIN_MARKER)
+        .ok_or_else(|| anyhow!("No {BEGIN_MARKER} line"))?
+        + BEGIN_MARKER.len();
+    let end = document[begin..]
+        .find(END_MARKER)
+        .ok_or_else(|| anyhow!("No {END_MARKER} line after {BEGIN_MARKER}"))?
+        + begin;
+    let line_ending = if document.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let table = table.replace('\n', line_ending);
+    Ok(format!(
+        "{}{line_ending}{line_ending}{table}{line_ending}{}",
+        &document[..begin],
+        &document[end..]
+    ))
+}
+
+pub(crate) fn run(args: &[String]) -> Result<()> {
+    let args = Args::parse(args, &[], &["redact"])?;
+    let runs = load_all_runs(artifact::folder())?;
+    let table = table(&runs, args.switch("redact"));
+    let [file] = args.positional() else {
+        if !args.positional().is_empty() {
+            return Err(anyhow!("Expected one file, not {:?}", args.positional()));
+        }
+        print!("{table}");
+        return Ok(());
+    };
+
+    let document = fs::read_to_string(file).with_context(|| format!("Reading {file}"))?;
+    let updated = replace_section(&document, &table).with_context(|| format!("Updating {file}"))?;
+    if updated == document {
+        println!("{file} is up to date");
+    } else {
+        fs::write(file, updated).with_context(|| format!("Writing {file}"))?;
+        println!("Updated the timings in {file}");
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use super::{replace_section, table};
+use crate::artifact::RunRecord;
+use chrono::NaiveDate;
+
+fn run(day: u8, hour: u32, build_config: &str, part2: &str) -> RunRecord {
+    RunRecord {
+        day,
+        timestamp: NaiveDate::from_ymd_opt(2022, 12, 20)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap(),
+        build_config: build_config.to_owned(),
+        part1: "1|2".to_owned(),
+        part2: part2.to_owned(),
+        duration: 1.5,
+        parse_duration: 0.25,
+        input: None,
+        environment: None,
+    }
+}
+
+#[test]
+fn markdown() {
+    let runs = [
+        run(1, 10, "Release", "old"),
+        run(1, 11, "Release", "45000"),
+        run(1, 12, "Debug", "debug"),
+        run(10, 10, "Release", "#.\n.#\n"),
+    ];
+    assert_eq!(
+        "| Day | Part 1 | Part 2 | Parse | Solve |
+|---:|---|---|---:|---:|
+| 1 | `1\\|2` | `45000` | 0.2500 ms | 1.50 ms |
+| 10 | `1\\|2` | *2-line picture* | 0.2500 ms | 1.50 ms |
+| **Total** | | | **0.5000 ms** | **3.00 ms** |
+",
+        table(&runs, false)
+    );
+    let redacted = table(&runs, true);
+    assert!(redacted.contains("| 1 | *hidden* | *hidden* |"));
+    assert!(!redacted.contains("45000"));
+}
+
+#[test]
+fn section() {
+    let document = "# Advent of Code\n\n<!-- aoc-timings:begin -->\nold table\n<!-- aoc-timings:end -->\n\nMore.\n";
+    let updated = replace_section(document, "| new |\n").unwrap();
+    assert_eq!(
+        "# Advent of Code\n\n<!-- aoc-timings:begin -->\n\n| new |\n\n<!-- aoc-timings:end -->\n\nMore.\n",
+        updated
+    );
+    assert_eq!(updated, replace_section(&updated, "| new |\n").unwrap());
+
+    let windows = document.replace('\n', "\r\n");
+    assert_eq!(
+        updated.replace('\n', "\r\n"),
+        replace_section(&windows, "| new |\n").unwrap()
+    );
+
+    assert!(replace_section("# No markers\n", "").is_err());
+    assert!(replace_section("<!-- aoc-timings:end -->\n<!-- aoc-timings:begin -->\n", "").is_err());
+}
@@ -0,0 +1,188 @@
+use crate::cli::Args;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, TimeZone, Utc};
+use std::{fs, path::Path};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const USAGE: &str = "today [--now RFC3339] [--src DIR]";
+
+/// Puzzles unlock at midnight US Eastern. December is always standard time, UTC-5.
+fn eastern() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+fn unlock_time(year: i32, day: u32) -> DateTime<Utc> {
+    eastern()
+        .with_ymd_and_hms(year, 12, day, 0, 0, 0)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+/// The most recently unlocked puzzle, if 'now' is during the event.
+pub(crate) fn puzzle_day(now: DateTime<Utc>) -> Option<u32> {
+    let local = now.with_timezone(&eastern());
+    (local.month() == 12 && local.day() <= 25).then(|| local.day())
+}
+
+/// When the next puzzle unlocks, and which day it is.
+pub(crate) fn next_unlock(now: DateTime<Utc>) -> (DateTime<Utc>, u32) {
+    let local = now.with_timezone(&eastern());
+    match puzzle_day(now) {
+        Some(day) if day < 25 => (unlock_time(local.year(), day + 1), day + 1),
+        _ if local.month() < 12 => (unlock_time(local.year(), 1), 1),
+        _ => (unlock_time(local.year() + 1, 1), 1),
+    }
+}
+
+pub(crate) fn format_countdown(duration: Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+    let (days, hours, minutes, seconds) = (
+        seconds / 86_400,
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+    );
+    if days > 0 {
+        format!("{days}d {hours:02}h {minutes:02}m {seconds:02}s")
+    } else {
+        format!("{hours:02}h {minutes:02}m {seconds:02}s")
+    }
+}
+
+/// Starting files for a new day, following the layout of the existing days.
+pub(crate) fn scaffold_files(day: u32) -> [(&'static str, String); 4] {
+    let module = format!(
+        "use self::input::INPUT;
+use crate::days::Solution;
+use anyhow::Result;
+use std::time::{{Duration, Instant}};
+
+pub(crate) mod generate;
+pub(crate) mod input;
+
+#[cfg(test)]
+mod tests;
+
+fn solve_for(input: &str) -> Result<(usize, usize, Duration)> {{
+    let timer = Instant::now();
+    let lines = input.lines().collect::<Vec<_>>();
+    let parse_duration = timer.elapsed();
+    let part1 = lines.len();
+    let part2 = 0;
+    Ok((part1, part2, parse_duration))
+}}
+
+pub(crate) fn solve() -> (usize, usize, Duration) {{
+    match solve_for(INPUT) {{
+        Ok(solution) => solution,
+        Err(error) => {{
+            println!(\"day {day} error: {{}}\", error);
+            (0, 0, Duration::new(0, 0))
+        }}
+    }}
+}}
+
+pub(crate) fn solve_input(input: &str) -> Result<Solution> {{
+    let (part1, part2, parse_duration) = solve_for(input)?;
+    Ok(Solution::new(part1, part2, parse_duration))
+}}
+"
+    );
+    let tests = "use super::solve_for;
+
+const INPUT: &str = \"\";
+
+#[test]
+fn part1() {
+    assert_eq!(0, solve_for(INPUT).unwrap().0);
+}
+
+#[test]
+fn part2() {
+    assert_eq!(0, solve_for(INPUT).unwrap().1);
+}
+"
+    .to_owned();
+    let input = "pub(crate) const INPUT: &str = \"\";\n".to_owned();
+    let generate = "use crate::generate::Rng;
+
+/// TODO produce valid inputs of about 'scale' times the size of the puzzle input.
+pub(crate) fn generate(_rng: &mut Rng, _scale: usize) -> String {
+    String::new()
+}
+"
+    .to_owned();
+    [
+        ("mod.rs", module),
+        ("tests.rs", tests),
+        ("input.rs", input),
+        ("generate.rs", generate),
+    ]
+}
+
+fn scaffold(src: &Path, day: u32) -> Result<()> {
+    let day_dir = src.join(format!("day{day:02}"));
+    fs::create_dir_all(&day_dir).with_context(|| format!("Creating {}", day_dir.display()))?;
+    for (name, contents) in scaffold_files(day) {
+        let path = day_dir.join(name);
+        // Match the line endings of the existing day sources.
+        fs::write(&path, contents.replace('\n', "\r\n"))
+            .with_context(|| format!("Writing {}", path.display()))?;
+    }
+    println!("Scaffolded {}. To finish setting it up:", day_dir.display());
+    println!("  Cargo.toml [features]:   day{day:02} = []");
+    println!("  src/lib.rs modules:      #[cfg(feature = \"day{day:02}\")] mod day{day:02};");
+    println!("  src/lib.rs solve_day:    #[cfg(feature = \"day{day:02}\")] {day} => Some(solve!(day{day:02})),");
+    println!(
+        "  src/days.rs DAYS:        #[cfg(feature = \"day{day:02}\")] day!({day}, day{day:02}),"
+    );
+    println!(
+        "Then paste the puzzle input into {}.",
+        day_dir.join("input.rs").display()
+    );
+    Ok(())
+}
+
+pub(crate) fn run(args: &[String], solve_day: fn(u8) -> Option<std::time::Duration>) -> Result<()> {
+    let args = Args::parse(args, &["now", "src"], &[])?;
+    let now = match args.value("now") {
+        Some(now) => DateTime::parse_from_rfc3339(now)
+            .with_context(|| format!("Bad --now: {now}"))?
+            .with_timezone(&Utc),
+        None => Utc::now(),
+    };
+
+    let (unlock, next_day) = next_unlock(now);
+    let countdown = format!(
+        "Day {next_day} unlocks in {} ({unlock}).",
+        format_countdown(unlock - now)
+    );
+
+    let Some(day) = puzzle_day(now) else {
+        println!(
+            "Advent of Code only runs from December 1st to 25th, so there is no puzzle today."
+        );
+        println!("{countdown}");
+        return Ok(());
+    };
+
+    println!(
+        "Today is day {day} of Advent of Code {}.",
+        now.with_timezone(&eastern()).year()
+    );
+    if solve_day(day as u8).is_none() {
+        let src = Path::new(args.value("src").unwrap_or("src"));
+        if src.join(format!("day{day:02}")).join("mod.rs").exists() {
+            return Err(anyhow!(
+                "Day {day} exists but isn't enabled. Run with --features day{day:02}."
+            ));
+        }
+        scaffold(src, day)?;
+    }
+    if day < 25 {
+        println!("{countdown}");
+    }
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use super::{format_countdown, next_unlock, puzzle_day, scaffold_files};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+fn utc(s: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+}
+
+#[test]
+fn day() {
+    // Day 1 unlocks at 05:00 UTC.
+    assert_eq!(None, puzzle_day(utc("2022-12-01T04:59:59Z")));
+    assert_eq!(Some(1), puzzle_day(utc("2022-12-01T05:00:00Z")));
+    assert_eq!(Some(15), puzzle_day(utc("2022-12-16T04:00:00Z")));
+    assert_eq!(Some(25), puzzle_day(utc("2022-12-26T04:59:59Z")));
+    assert_eq!(None, puzzle_day(utc("2022-12-26T05:00:00Z")));
+    assert_eq!(None, puzzle_day(utc("2022-07-04T12:00:00Z")));
+}
+
+#[test]
+fn unlock() {
+    assert_eq!(
+        (Utc.with_ymd_and_hms(2022, 12, 17, 5, 0, 0).unwrap(), 17),
+        next_unlock(utc("2022-12-16T12:00:00Z"))
+    );
+    assert_eq!(
+        (Utc.with_ymd_and_hms(2022, 12, 1, 5, 0, 0).unwrap(), 1),
+        next_unlock(utc("2022-11-30T23:00:00Z"))
+    );
+    assert_eq!(
+        (Utc.with_ymd_and_hms(2023, 12, 1, 5, 0, 0).unwrap(), 1),
+        next_unlock(utc("2022-12-25T12:00:00Z"))
+    );
+    assert_eq!(
+        (Utc.with_ymd_and_hms(2023, 12, 1, 5, 0, 0).unwrap(), 1),
+        next_unlock(utc("2022-12-31T12:00:00Z"))
+    );
+}
+
+#[test]
+fn countdown() {
+    assert_eq!("00h 00m 00s", format_countdown(Duration::zero()));
+    assert_eq!(
+        "05h 03m 12s",
+        format_countdown(Duration::seconds(5 * 3600 + 3 * 60 + 12))
+    );
+    assert_eq!("2d 01h 00m 00s", format_countdown(Duration::hours(49)));
+}
+
+#[test]
+fn scaffold() {
+    let files = scaffold_files(17);
+    let module = &files.iter().find(|(name, _)| *name == "mod.rs").unwrap().1;
+    assert!(module.contains("println!(\"day 17 error: {}\", error);"));
+    assert!(module.contains("pub(crate) fn solve_input(input: &str) -> Result<Solution> {"));
+}
@@ -0,0 +1,356 @@
+//! Pictures of a solver at work, played back in a terminal or written out as numbered images.
+//! Days call [Player::show] as they go, which does nothing unless the runner was started with
+//! `--visualize`.
+
+use crate::grid::Grid;
+use anyhow::{anyhow, Result};
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    sync::OnceLock,
+    thread,
+    time::Duration,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) const USAGE: &str = "[--visualize[=terminal|ppm|pgm]] [--frames-dir DIR]";
+
+/// Where image sequences go unless `--frames-dir` says otherwise.
+pub(crate) const FRAMES_FOLDER: &str = "frames";
+
+/// The time between terminal frames.
+#[cfg_attr(not(all_days), allow(dead_code))]
+const FRAME_DELAY: Duration = Duration::from_millis(30);
+
+/// Image pixels per cell, so small grids are still visible.
+#[cfg_attr(not(all_days), allow(dead_code))]
+const IMAGE_SCALE: usize = 4;
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Rgb {
+    pub(crate) r: u8,
+    pub(crate) g: u8,
+    pub(crate) b: u8,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl Rgb {
+    pub(crate) const BLACK: Self = Self::new(0, 0, 0);
+    pub(crate) const WHITE: Self = Self::new(255, 255, 255);
+    pub(crate) const GREY: Self = Self::new(128, 128, 128);
+    pub(crate) const RED: Self = Self::new(220, 50, 47);
+    pub(crate) const GREEN: Self = Self::new(70, 160, 60);
+    pub(crate) const YELLOW: Self = Self::new(230, 190, 60);
+
+    pub(crate) const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Perceived brightness, as grey image formats store it.
+    pub(crate) fn luminance(self) -> u8 {
+        ((self.r as u32 * 299 + self.g as u32 * 587 + self.b as u32 * 114) / 1000) as u8
+    }
+
+    /// Part of the way from 'self' to 'other', where 0 is 'self' and 1 is 'other'.
+    pub(crate) fn blend(self, other: Self, fraction: f64) -> Self {
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * fraction).round() as u8;
+        Self::new(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+}
+
+/// One cell of a frame: a character for terminals and a colour for both.
+#[cfg_attr(not(all_days), allow(dead_code))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Pixel {
+    pub(crate) glyph: char,
+    pub(crate) color: Rgb,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl Pixel {
+    pub(crate) const BLANK: Self = Self::new(' ', Rgb::BLACK);
+
+    pub(crate) const fn new(glyph: char, color: Rgb) -> Self {
+        Self { glyph, color }
+    }
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) type Frame = Grid<Pixel>;
+
+/// Something that can be pictured, such as a solver's state.
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) trait Render {
+    fn render(&self) -> Frame;
+}
+
+/// A grid of pixels pictures itself.
+impl Render for Frame {
+    fn render(&self) -> Frame {
+        self.clone()
+    }
+}
+
+/// A closure draws its frame only when asked, so it costs nothing when visualizing is off.
+impl<F: Fn() -> Frame> Render for F {
+    fn render(&self) -> Frame {
+        self()
+    }
+}
+
+/// Somewhere frames go.
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) trait Backend {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+/// Plays frames back in place in a terminal, using 24-bit ANSI colours.
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) struct Terminal<W: Write> {
+    out: W,
+    delay: Duration,
+    started: bool,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl<W: Write> Terminal<W> {
+    pub(crate) fn new(out: W, delay: Duration) -> Self {
+        Self {
+            out,
+            delay,
+            started: false,
+        }
+    }
+}
+
+impl<W: Write> Backend for Terminal<W> {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut text = String::new();
+        if !self.started {
+            // Clear the screen once, then draw each frame over the last.
+            text.push_str("\x1b[2J");
+            self.started = true;
+        }
+        text.push_str("\x1b[H");
+        for row in frame.rows() {
+            let mut color = None;
+            for pixel in row {
+                if color != Some(pixel.color) {
+                    let Rgb { r, g, b } = pixel.color;
+                    text.push_str(&format!("\x1b[38;2;{r};{g};{b}m"));
+                    color = Some(pixel.color);
+                }
+                text.push(pixel.glyph);
+            }
+            text.push_str("\x1b[0m\n");
+        }
+        self.out.write_all(text.as_bytes())?;
+        self.out.flush()?;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ImageFormat {
+    /// Colour.
+    Ppm,
+    /// Grey.
+    Pgm,
+}
+
+/// Encode a frame as a binary PPM or PGM image, with each cell a 'scale'-pixel square.
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) fn encode(frame: &Frame, format: ImageFormat, scale: usize) -> Vec<u8> {
+    let (magic, channels) = match format {
+        ImageFormat::Ppm => ("P6", 3),
+        ImageFormat::Pgm => ("P5", 1),
+    };
+    let width = frame.width() * scale;
+    let height = frame.height() * scale;
+    let mut image = format!("{magic}\n{width} {height}\n255\n").into_bytes();
+    image.reserve(width * height * channels);
+    for row in frame.rows() {
+        let mut line = Vec::with_capacity(width * channels);
+        for pixel in row {
+            let color = pixel.color;
+            for _ in 0..scale {
+                match format {
+                    ImageFormat::Ppm => line.extend([color.r, color.g, color.b]),
+                    ImageFormat::Pgm => line.push(color.luminance()),
+                }
+            }
+        }
+        for _ in 0..scale {
+            image.extend(&line);
+        }
+    }
+    image
+}
+
+/// Writes each frame to its own numbered image file.
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) struct Images {
+    folder: PathBuf,
+    format: ImageFormat,
+    scale: usize,
+    count: usize,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl Images {
+    pub(crate) fn new(folder: PathBuf, format: ImageFormat, scale: usize) -> Self {
+        Self {
+            folder,
+            format,
+            scale,
+            count: 0,
+        }
+    }
+}
+
+impl Backend for Images {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()> {
+        if self.count == 0 {
+            fs::create_dir_all(&self.folder)?;
+        }
+        let extension = match self.format {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Pgm => "pgm",
+        };
+        let path = self
+            .folder
+            .join(format!("frame_{:05}.{extension}", self.count));
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(&encode(frame, self.format, self.scale))?;
+        file.flush()?;
+        self.count += 1;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Output {
+    Terminal,
+    Images(ImageFormat),
+}
+
+/// Whether and where to visualize.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Settings {
+    pub(crate) output: Option<Output>,
+    pub(crate) folder: PathBuf,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            output: None,
+            folder: PathBuf::from(FRAMES_FOLDER),
+        }
+    }
+}
+
+static SETTINGS: OnceLock<Settings> = OnceLock::new();
+
+impl Settings {
+    /// Take the visualization options from the start of 'args', returning the settings and the
+    /// rest of the arguments.
+    pub(crate) fn from_args(args: &[String]) -> Result<(Self, &[String])> {
+        let mut settings = Self::default();
+        let mut args = args;
+        while let Some(arg) = args.first() {
+            if let Some(output) = arg.strip_prefix("--visualize") {
+                settings.output = Some(match output {
+                    "" | "=terminal" => Output::Terminal,
+                    "=ppm" => Output::Images(ImageFormat::Ppm),
+                    "=pgm" => Output::Images(ImageFormat::Pgm),
+                    _ => return Err(anyhow!("Unknown visualization: {arg}")),
+                });
+            } else if arg == "--frames-dir" {
+                let folder = args
+                    .get(1)
+                    .ok_or_else(|| anyhow!("--frames-dir needs a value"))?;
+                settings.folder = PathBuf::from(folder);
+                args = &args[1..];
+            } else if let Some(folder) = arg.strip_prefix("--frames-dir=") {
+                settings.folder = PathBuf::from(folder);
+            } else {
+                break;
+            }
+            args = &args[1..];
+        }
+        Ok((settings, args))
+    }
+
+    /// Use these settings for the rest of the run. Only the first call has any effect.
+    pub(crate) fn apply(self) {
+        let _ = SETTINGS.set(self);
+    }
+
+    /// The settings in use, which are off if they haven't been set.
+    pub(crate) fn current() -> &'static Self {
+        SETTINGS.get_or_init(Self::default)
+    }
+}
+
+/// Shows a day's frames, if visualizing is on. Rendering is skipped when it's off, so days can
+/// show frames freely.
+#[cfg_attr(not(all_days), allow(dead_code))]
+pub(crate) struct Player {
+    name: String,
+    backend: Option<Box<dyn Backend>>,
+}
+
+#[cfg_attr(not(all_days), allow(dead_code))]
+impl Player {
+    /// A player for 'name', such as `day14`, which names the folder its images go in.
+    pub(crate) fn new(name: &str) -> Self {
+        let settings = Settings::current();
+        let backend = settings.output.map(|output| -> Box<dyn Backend> {
+            match output {
+                Output::Terminal => Box::new(Terminal::new(io::stdout(), FRAME_DELAY)),
+                Output::Images(format) => {
+                    Box::new(Images::new(settings.folder.join(name), format, IMAGE_SCALE))
+                }
+            }
+        });
+        Self {
+            name: name.to_owned(),
+            backend,
+        }
+    }
+
+    /// A player with a backend of its own, whatever the settings.
+    #[cfg(test)]
+    pub(crate) fn with_backend(name: &str, backend: Box<dyn Backend>) -> Self {
+        Self {
+            name: name.to_owned(),
+            backend: Some(backend),
+        }
+    }
+
+    pub(crate) fn is_on(&self) -> bool {
+        self.backend.is_some()
+    }
+
+    /// Show a frame of 'scene'. A failure turns the player off with a warning, since the answers
+    /// matter more than the pictures.
+    pub(crate) fn show(&mut self, scene: &impl Render) {
+        let Some(backend) = &mut self.backend else {
+            return;
+        };
+        if let Err(error) = backend.frame(&scene.render()) {
+            eprintln!("warning: stopped visualizing {}: {error}", self.name);
+            self.backend = None;
+        }
+    }
+}
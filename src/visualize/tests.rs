@@ -0,0 +1,116 @@
+use super::{
+    encode, Backend, Frame, ImageFormat, Images, Output, Pixel, Player, Rgb, Settings, Terminal,
+};
+use crate::{geometry::Point, grid::Grid};
+use std::{cell::Cell, env, fs, io, path::PathBuf, rc::Rc, time::Duration};
+
+fn frame() -> Frame {
+    let mut frame = Grid::new(2, 1, Pixel::BLANK);
+    frame[Point::new(1, 0)] = Pixel::new('#', Rgb::new(10, 20, 30));
+    frame
+}
+
+#[test]
+fn colors() {
+    assert_eq!(0, Rgb::BLACK.luminance());
+    assert_eq!(255, Rgb::WHITE.luminance());
+    assert_eq!(Rgb::new(128, 128, 128), Rgb::BLACK.blend(Rgb::WHITE, 0.5));
+    assert_eq!(Rgb::RED, Rgb::RED.blend(Rgb::GREEN, 0.0));
+    assert_eq!(Rgb::GREEN, Rgb::RED.blend(Rgb::GREEN, 1.0));
+}
+
+#[test]
+fn images() {
+    let ppm = encode(&frame(), ImageFormat::Ppm, 1);
+    assert_eq!(b"P6\n2 1\n255\n\0\0\0\x0a\x14\x1e"[..], ppm[..]);
+    let pgm = encode(&frame(), ImageFormat::Pgm, 2);
+    assert_eq!(b"P5\n4 2\n255\n\0\0\x12\x12\0\0\x12\x12"[..], pgm[..]);
+
+    let folder = env::temp_dir().join(format!("aoc-2022-frames-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&folder);
+    let mut images = Images::new(folder.join("day99"), ImageFormat::Pgm, 1);
+    images.frame(&frame()).unwrap();
+    images.frame(&frame()).unwrap();
+    assert_eq!(
+        encode(&frame(), ImageFormat::Pgm, 1),
+        fs::read(folder.join("day99/frame_00001.pgm")).unwrap()
+    );
+    assert_eq!(2, fs::read_dir(folder.join("day99")).unwrap().count());
+    fs::remove_dir_all(&folder).unwrap();
+}
+
+#[test]
+fn terminal() {
+    let mut out = vec![];
+    let mut terminal = Terminal::new(&mut out, Duration::ZERO);
+    terminal.frame(&frame()).unwrap();
+    terminal.frame(&frame()).unwrap();
+    let picture = "\x1b[H\x1b[38;2;0;0;0m \x1b[38;2;10;20;30m#\x1b[0m\n";
+    assert_eq!(
+        format!("\x1b[2J{picture}{picture}"),
+        String::from_utf8(out).unwrap()
+    );
+}
+
+#[test]
+fn settings() {
+    let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+    let all = args(&["--visualize=ppm", "--frames-dir", "out", "today"]);
+    let (settings, rest) = Settings::from_args(&all).unwrap();
+    assert_eq!(
+        Settings {
+            output: Some(Output::Images(ImageFormat::Ppm)),
+            folder: PathBuf::from("out"),
+        },
+        settings
+    );
+    assert_eq!(args(&["today"]), rest);
+
+    let all = args(&["--visualize"]);
+    let (settings, rest) = Settings::from_args(&all).unwrap();
+    assert_eq!(Some(Output::Terminal), settings.output);
+    assert!(rest.is_empty());
+    let (settings, _) = Settings::from_args(&[]).unwrap();
+    assert_eq!(Settings::default(), settings);
+
+    assert!(Settings::from_args(&args(&["--visualize=gif"])).is_err());
+    assert!(Settings::from_args(&args(&["--frames-dir"])).is_err());
+}
+
+/// Counts frames, failing once it has seen 'limit' of them.
+struct Counter {
+    frames: Rc<Cell<usize>>,
+    limit: usize,
+}
+
+impl Backend for Counter {
+    fn frame(&mut self, _: &Frame) -> io::Result<()> {
+        if self.frames.get() == self.limit {
+            return Err(io::Error::other("full"));
+        }
+        self.frames.set(self.frames.get() + 1);
+        Ok(())
+    }
+}
+
+#[test]
+fn player() {
+    // Visualizing is off unless the runner turns it on, so nothing is rendered.
+    let mut player = Player::new("day99");
+    assert!(!player.is_on());
+    player.show(&|| -> Frame { panic!("rendered while off") });
+
+    let frames = Rc::new(Cell::new(0));
+    let counter = Counter {
+        frames: frames.clone(),
+        limit: 2,
+    };
+    let mut player = Player::with_backend("day99", Box::new(counter));
+    for _ in 0..4 {
+        player.show(&frame());
+    }
+    // The failed third frame turned the player off.
+    assert_eq!(2, frames.get());
+    assert!(!player.is_on());
+}